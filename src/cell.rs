@@ -1,3 +1,5 @@
+use crate::error::SudokuError;
+use crate::{value_to_char, Position};
 use colored::Colorize;
 use std::fmt;
 use std::fmt::Formatter;
@@ -5,21 +7,22 @@ use std::fmt::Formatter;
 //static GROUPS: [&[[usize; 9]; 9]; 3] = [&ROWS, &COLS, &REGS];
 #[derive(Copy, Clone)]
 pub struct Cell {
-    pub(crate) candidates: u16,
+    pub(crate) candidates: u32,
     pub(crate) value: u8,
     pub(crate) answer: Option<u8>,
     pub(crate) is_given: bool,
     pub(crate) is_dirty: bool,
+    pub(crate) position: Position,
 }
 
 impl fmt::Debug for Cell {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let mut candidates = self.candidates;
-        let mut val = 1i8;
+        let mut val = 1u8;
         let mut accumulate = "".to_string();
         while candidates != 0 {
             accumulate += &*if (candidates & 1) == 1 {
-                val.to_string()
+                value_to_char(val).to_string()
             } else {
                 "-".to_string()
             };
@@ -30,6 +33,29 @@ impl fmt::Debug for Cell {
     }
 }
 
+// region Getters
+impl Cell {
+    /// The solved value of this cell, or 0 if it is still unknown
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+    /// Bitset of the values this cell could still be, where bit 0 is the value 1
+    pub fn candidates(&self) -> u32 {
+        self.candidates
+    }
+    /// The known solution for this cell, if the board was created with one
+    pub fn answer(&self) -> Option<u8> {
+        self.answer
+    }
+    pub fn is_given(&self) -> bool {
+        self.is_given
+    }
+    pub fn position(&self) -> Position {
+        self.position
+    }
+}
+// endregion Getters
+
 #[allow(dead_code)]
 impl Cell {
     fn color_card(&self, card: String) -> String {
@@ -47,39 +73,18 @@ impl Cell {
         } else {
             return card;
         }
-        return result.join("\n").to_string();
+        result.join("\n").to_string()
     }
-    pub fn get_print_card(&self) -> String {
-        if self.value == 0 {
-            let mut base = format!("{:?}", self);
-            base.insert(6, '\n');
-            base.insert(3, '\n');
-            base = base.replace('-', " ");
-            base = self.color_card(base);
-            base
-        } else {
-            const NUMBERS: [&str; 9] = [
-                " ┓ \n ┃ \n ┻ ",
-                "┏━┓\n┏━┛\n┗━━",
-                "┏━┓\n ━┫\n┗━┛",
-                "╻ ╻\n┗━╋\n  ╹",
-                "┏━╸\n┗━┓\n┗━┛",
-                "┏━┓\n┣━┓\n┗━┛",
-                "╺━┓\n  ┃\n  ╹",
-                "┏━┓\n┣━┫\n┗━┛",
-                "┏━┓\n┗━┫\n┗━┛",
-            ];
-            let result = NUMBERS[self.value as usize - 1];
-            self.color_card(result.to_string())
-        }
+    pub fn get_print_card(&self, box_rows: usize, box_cols: usize) -> String {
+        self.color_card(print_card(self.value, self.candidates, box_rows, box_cols))
     }
     pub fn contains_value(&self, value: u8) -> bool {
-        if self.value <= 0 {
-            return (self.candidates & (1 << value - 1)) > 0;
+        if self.value == 0 {
+            return (self.candidates & (1 << (value - 1))) > 0;
         }
         false
     }
-    pub fn promote_single_candidate(&mut self) -> bool {
+    pub fn promote_single_candidate(&mut self) -> Result<bool, SudokuError> {
         let mut val = 0u8;
         let mut possibilities = self.candidates;
         let mut bits_set = 0;
@@ -91,38 +96,54 @@ impl Cell {
             val += 1;
         }
         if bits_set == 1 {
-            self.set_value(val);
-            return true;
+            self.set_value(val)?;
+            return Ok(true);
         }
-        false
+        Ok(false)
     }
-    pub(crate) fn remove_possibilities(&mut self, bits: u16) -> bool {
+    pub(crate) fn remove_possibilities(&mut self, bits: u32) -> Result<bool, SudokuError> {
         if bits & self.candidates != 0 {
             self.candidates &= !bits;
-            self.is_answer_possible();
             self.is_dirty = true;
-            return true;
+            self.check_candidates()?;
+            return Ok(true);
         }
-        false
+        Ok(false)
     }
-    pub(crate) fn remove_possibility(&mut self, value: u8) -> bool {
+    pub(crate) fn remove_possibility(&mut self, value: u8) -> Result<bool, SudokuError> {
         let bit = 1 << (value - 1);
         if self.candidates & bit != 0 {
             self.candidates &= !bit;
-            self.is_answer_possible();
-            return true;
+            self.check_candidates()?;
+            return Ok(true);
         }
-        false
+        Ok(false)
+    }
+    // Ensures removing candidates didn't leave this cell unsolvable
+    fn check_candidates(&self) -> Result<(), SudokuError> {
+        if self.value == 0 && self.candidates == 0 {
+            return Err(SudokuError::Contradiction {
+                position: self.position,
+            });
+        }
+        self.is_answer_possible()
     }
 
-    pub fn is_answer_possible(&self) {
-        if self.answer.is_none() || self.value > 0 {
-            return;
+    pub fn is_answer_possible(&self) -> Result<(), SudokuError> {
+        let Some(ans) = self.answer else {
+            return Ok(());
+        };
+        if self.value > 0 {
+            return Ok(());
         }
-        let ans = self.answer.unwrap();
         if !self.contains_value(ans) {
-            panic!("REMOVED ANSWER AS POSSIBILITY")
+            return Err(SudokuError::AnswerMismatch {
+                position: self.position,
+                expected: ans,
+                found: None,
+            });
         }
+        Ok(())
     }
     pub fn get_possibilities(&self) -> Vec<u16> {
         if self.value != 0 {
@@ -130,25 +151,68 @@ impl Cell {
         }
         let mut possibilities = self.candidates;
         let mut results = Vec::new();
-        for i in 0..9 {
+        let mut value = 1;
+        while possibilities != 0 {
             if possibilities & 1 == 1 {
-                results.push(i + 1);
+                results.push(value);
             }
             possibilities >>= 1;
+            value += 1;
         }
         results
     }
-    pub(crate) fn set_value(&mut self, value: u8) {
-        if self.answer.is_some() {
-            if value != self.answer.unwrap() {
-                panic!(
-                    "INVALID ANSWER: should be {:?}, is {value}",
-                    self.answer.unwrap()
-                );
-            }
+    pub(crate) fn set_value(&mut self, value: u8) -> Result<(), SudokuError> {
+        if let Some(answer) = self.answer
+            && value != answer
+        {
+            return Err(SudokuError::AnswerMismatch {
+                position: self.position,
+                expected: answer,
+                found: Some(value),
+            });
         }
         self.value = value;
         self.candidates = 0;
         self.is_dirty = true;
+        Ok(())
+    }
+}
+/// The card used to draw a cell, as many lines as a box has rows, and as wide as a box has columns,
+/// so every candidate gets its own spot. On a 9x9 board values are drawn as large digits, on any
+/// other size they are a single character in the middle of the card
+pub fn print_card(value: u8, candidates: u32, box_rows: usize, box_cols: usize) -> String {
+    if value != 0 && box_rows == 3 && box_cols == 3 {
+        const NUMBERS: [&str; 9] = [
+            " ┓ \n ┃ \n ┻ ",
+            "┏━┓\n┏━┛\n┗━━",
+            "┏━┓\n ━┫\n┗━┛",
+            "╻ ╻\n┗━╋\n  ╹",
+            "┏━╸\n┗━┓\n┗━┛",
+            "┏━┓\n┣━┓\n┗━┛",
+            "╺━┓\n  ┃\n  ╹",
+            "┏━┓\n┣━┫\n┗━┛",
+            "┏━┓\n┗━┫\n┗━┛",
+        ];
+        return NUMBERS[value as usize - 1].to_string();
+    }
+    let slots = box_rows * box_cols;
+    let mut card = (0..slots)
+        .map(|slot| {
+            if value != 0 {
+                if slot == box_rows / 2 * box_cols + (box_cols - 1) / 2 {
+                    value_to_char(value)
+                } else {
+                    ' '
+                }
+            } else if candidates & (1 << slot) != 0 {
+                value_to_char(slot as u8 + 1)
+            } else {
+                ' '
+            }
+        })
+        .collect::<String>();
+    for line in (1..box_rows).rev() {
+        card.insert(line * box_cols, '\n');
     }
+    card
 }
@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use std::env::args;
+use std::io::stdin;
+
+pub struct CommandArgs {
+    arg_map: HashMap<String, String>,
+}
+impl CommandArgs {
+    pub fn new() -> CommandArgs {
+        let mut arg_map: HashMap<String, String> = Default::default();
+        for arg in args().skip(1) {
+            match arg.split_once("=") {
+                None => {
+                    arg_map.insert(arg.clone(), "".to_string());
+                }
+                Some((key, value)) => {
+                    arg_map.insert(key.to_string(), value.to_string());
+                }
+            }
+        }
+        CommandArgs { arg_map }
+    }
+    pub fn get_arg(&self, key: &str) -> Option<&String> {
+        self.arg_map.get(key)
+    }
+    pub fn has_arg(&self, key: &str) -> bool {
+        self.arg_map.contains_key(key)
+    }
+}
+pub fn parse_yes_no(input: &str) -> Option<bool> {
+    let mut start = input.chars().next()?;
+    start = start.to_ascii_lowercase();
+    if start == 'n' || start == 'f' {
+        return Some(false);
+    } else if start == 'y' || start == 't' {
+        return Some(true);
+    }
+    None
+}
+pub fn query_args_or_user<P, T>(
+    prompt: &str,
+    failure_message: &str,
+    arg_flag: &str,
+    arguments: &CommandArgs,
+    mut validity_test: P,
+) -> (String, T)
+where
+    P: FnMut(&str) -> Option<T>,
+{
+    if let Some(arg) = arguments.get_arg(arg_flag) {
+        if let Some(validity) = validity_test(arg) {
+            return (arg.to_string(), validity);
+        }
+        println!("{}", failure_message);
+    }
+    loop {
+        println!("{}", prompt);
+        let mut result = String::new();
+        stdin().read_line(&mut result).expect("Failed to read line");
+        if let Some(validity) = validity_test(&result) {
+            return (result, validity);
+        }
+        println!("{}", failure_message);
+    }
+}
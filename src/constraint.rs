@@ -0,0 +1,195 @@
+//! Rules a variant adds to a board that aren't houses, like the sum of a killer cage
+use crate::grid::Grid;
+use crate::Position;
+use std::fmt;
+
+/// A rule on some of the board's cells on top of every house holding each value once. Extra houses
+/// are added with [`Grid::add_house`] and every technique uses them, constraints only take part
+/// through the [`CONSTRAINT`](crate::solvers::CONSTRAINT) technique and the brute force search.
+/// Displaying a constraint should describe it for a deduction, e.g. "Cage r1c1, r1c2 adds up to 7"
+pub trait Constraint: fmt::Display + Send + Sync {
+    /// The cells the constraint is about, as indices into [`Grid::cells`]
+    fn cells(&self) -> &[usize];
+    /// Whether the values placed so far could still be finished without breaking the constraint,
+    /// the values are indexed like [`Grid::cells`], with 0 for an empty cell
+    fn allows(&self, values: &[u8]) -> bool;
+    /// The values one of the constraint's cells could still take given the values placed so far,
+    /// indexed like [`Grid::cells`], as a candidate bitset. The brute force search uses it to cut
+    /// down what it tries, so it only has to rule out what's quick to work out. By default every
+    /// value is allowed
+    fn allowed_values(&self, _values: &[u8], _index: usize) -> u32 {
+        u32::MAX
+    }
+    /// The candidates each of the constraint's cells can still have, given the rest of the board,
+    /// in the same order as [`Constraint::cells`]. By default nothing is ruled out
+    fn restrict(&self, grid: &Grid) -> Vec<u32> {
+        self.cells()
+            .iter()
+            .map(|&index| grid.cells[index].candidates())
+            .collect()
+    }
+}
+
+/// A killer cage, cells that can't repeat a value and have to add up to the cage's sum. Cages are
+/// added with [`Grid::add_cage`]
+#[derive(Clone, Debug)]
+pub struct Cage {
+    cells: Vec<usize>,
+    positions: Vec<Position>,
+    sum: usize,
+    // The largest value on the board
+    size: usize,
+}
+impl Cage {
+    pub(crate) fn new(grid: &Grid, cells: Vec<usize>, sum: usize) -> Cage {
+        Cage {
+            positions: cells.iter().map(|&index| grid.position(index)).collect(),
+            cells,
+            sum,
+            size: grid.size(),
+        }
+    }
+    /// What the values of the cage's cells add up to
+    pub fn sum(&self) -> usize {
+        self.sum
+    }
+    // The values placed in the cage so far, what they add up to, and how many cells are empty, or
+    // None if a value repeats or the values already add up to more than the sum
+    fn placed(&self, values: &[u8]) -> Option<(u32, usize, usize)> {
+        let (mut used, mut total, mut empty) = (0u32, 0, 0);
+        for &index in self.cells.iter() {
+            let value = values[index];
+            if value == 0 {
+                empty += 1;
+                continue;
+            }
+            let bit = 1 << (value - 1);
+            if used & bit != 0 {
+                return None;
+            }
+            used |= bit;
+            total += value as usize;
+        }
+        (total <= self.sum).then_some((used, total, empty))
+    }
+    // Whether `count` different values the cage hasn't used yet can add up to `left`
+    fn can_make(&self, used: u32, count: usize, left: usize) -> bool {
+        let unused = (1..=self.size).filter(|value| used & (1 << (value - 1)) == 0);
+        let least = unused.clone().take(count).sum::<usize>();
+        let most = unused.rev().take(count).sum::<usize>();
+        (least..=most).contains(&left)
+    }
+    /// The values each cell can take in some way of filling the cage with different values that
+    /// add up to its sum, given the values each cell could be, in the same order as the cells
+    pub(crate) fn possible_values(&self, options: &[u32]) -> Vec<u32> {
+        let mut sets = Vec::new();
+        value_sets(self.size, options.len(), self.sum, 1, 0, &mut sets);
+        let mut possible = vec![0; options.len()];
+        for values in sets {
+            for (cell, &cell_options) in options.iter().enumerate() {
+                let mut untried = cell_options & values & !possible[cell];
+                while untried != 0 {
+                    let bit = untried & untried.wrapping_neg();
+                    untried &= untried - 1;
+                    if can_match(options, 0, cell, values & !bit) {
+                        possible[cell] |= bit;
+                    }
+                }
+            }
+        }
+        possible
+    }
+}
+// Finds every set of `count` different values from `from` up to `size` that adds up to `sum`
+fn value_sets(size: usize, count: usize, sum: usize, from: usize, set: u32, sets: &mut Vec<u32>) {
+    if count == 0 {
+        if sum == 0 {
+            sets.push(set);
+        }
+        return;
+    }
+    for value in from..=size {
+        // The rest of the values are bigger than this one, so there's a least they add up to, and
+        // a most if they're as big as they can be
+        let least = count * value + count * (count - 1) / 2;
+        let most = value + (count - 1) * size - (count - 1) * count.saturating_sub(2) / 2;
+        if least > sum {
+            break;
+        }
+        if most < sum {
+            continue;
+        }
+        value_sets(
+            size,
+            count - 1,
+            sum - value,
+            value + 1,
+            set | 1 << (value - 1),
+            sets,
+        );
+    }
+}
+// Whether every cell from `cell` on, apart from `skip`, can take a different one of the values
+fn can_match(options: &[u32], cell: usize, skip: usize, values: u32) -> bool {
+    if cell == options.len() {
+        return true;
+    }
+    if cell == skip {
+        return can_match(options, cell + 1, skip, values);
+    }
+    let mut choices = options[cell] & values;
+    while choices != 0 {
+        let bit = choices & choices.wrapping_neg();
+        choices &= choices - 1;
+        if can_match(options, cell + 1, skip, values & !bit) {
+            return true;
+        }
+    }
+    false
+}
+impl Constraint for Cage {
+    fn cells(&self) -> &[usize] {
+        &self.cells
+    }
+    fn allows(&self, values: &[u8]) -> bool {
+        match self.placed(values) {
+            Some((used, total, empty)) => self.can_make(used, empty, self.sum - total),
+            None => false,
+        }
+    }
+    fn allowed_values(&self, values: &[u8], _index: usize) -> u32 {
+        let Some((used, total, empty)) = self.placed(values) else {
+            return 0;
+        };
+        let left = self.sum - total;
+        (1..=self.size.min(left))
+            .map(|value| 1 << (value - 1))
+            .filter(|&bit| used & bit == 0)
+            .filter(|&bit| {
+                let value = bit.trailing_zeros() as usize + 1;
+                self.can_make(used | bit, empty.saturating_sub(1), left - value)
+            })
+            .fold(0, |allowed, bit| allowed | bit)
+    }
+    fn restrict(&self, grid: &Grid) -> Vec<u32> {
+        let options = self
+            .cells
+            .iter()
+            .map(|&index| match grid.cells[index].value() {
+                0 => grid.cells[index].candidates(),
+                value => 1 << (value - 1),
+            })
+            .collect::<Vec<u32>>();
+        self.possible_values(&options)
+    }
+}
+impl fmt::Display for Cage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells = self
+            .positions
+            .iter()
+            .map(|pos| pos.to_string())
+            .collect::<Vec<String>>();
+        write!(f, "Cage {} adds up to {}", cells.join(", "), self.sum)
+    }
+}
@@ -0,0 +1,164 @@
+//! Records of what a single solving step found, and what it changed on the board
+use crate::cell::Cell;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::Solver;
+use crate::{GroupType, Position, MAX_SIZE};
+use itertools::Itertools;
+use std::fmt;
+use std::fmt::Formatter;
+
+/// A single deduction made by a solver: the technique used, the cells and values of the pattern that
+/// justify it, and the values placed and candidates removed because of it
+#[derive(Clone, Debug)]
+pub struct Deduction {
+    /// The technique that made the deduction
+    pub technique: Solver,
+    /// The cells making up the pattern the technique found
+    pub pattern_cells: Vec<Position>,
+    /// The values the pattern is about
+    pub pattern_values: Vec<u8>,
+    /// Values filled into cells
+    pub placements: Vec<(Position, u8)>,
+    /// Candidates removed from cells, apart from the ones a placement removes from the cells that
+    /// see it
+    pub eliminations: Vec<(Position, u8)>,
+    /// Anything else the technique needs to explain itself, like the chain it followed
+    pub detail: Option<String>,
+}
+impl Deduction {
+    /// Starts an empty deduction for a solver to fill in as it makes its step
+    pub(crate) fn new(technique: &Solver) -> Deduction {
+        Deduction {
+            technique: *technique,
+            pattern_cells: Vec::new(),
+            pattern_values: Vec::new(),
+            placements: Vec::new(),
+            eliminations: Vec::new(),
+            detail: None,
+        }
+    }
+    /// Adds cells and values to the pattern, adding to anything noted earlier in the same step
+    pub(crate) fn add_pattern(&mut self, cells: &[Cell], indices: &[usize], values: u32) {
+        for &index in indices {
+            let position = cells[index].position;
+            if !self.pattern_cells.contains(&position) {
+                self.pattern_cells.push(position);
+            }
+        }
+        self.pattern_cells.sort();
+        for value in (1..=MAX_SIZE as u8).filter(|value| values & (1 << (value - 1)) != 0) {
+            if !self.pattern_values.contains(&value) {
+                self.pattern_values.push(value);
+            }
+        }
+        self.pattern_values.sort();
+    }
+    /// Removes candidates from a cell, recording each one that was still there
+    pub(crate) fn remove_possibilities(
+        &mut self,
+        cell: &mut Cell,
+        values: u32,
+    ) -> Result<bool, SudokuError> {
+        let removed = cell.candidates & values;
+        for value in (1..=MAX_SIZE as u8).filter(|value| removed & (1 << (value - 1)) != 0) {
+            self.eliminations.push((cell.position, value));
+        }
+        cell.remove_possibilities(values)
+    }
+    pub(crate) fn remove_possibility(
+        &mut self,
+        cell: &mut Cell,
+        value: u8,
+    ) -> Result<bool, SudokuError> {
+        self.remove_possibilities(cell, 1 << (value - 1))
+    }
+    /// Fills in a cell, recording the placement but not the candidates it removes from the cells
+    /// that see it
+    pub(crate) fn set_cell(
+        &mut self,
+        grid: &mut Grid,
+        position: Position,
+        value: u8,
+    ) -> Result<(), SudokuError> {
+        grid.set_cell(position, value)?;
+        self.placements.push((position, value));
+        Ok(())
+    }
+    /// The deduction if the step changed anything, or None if it didn't
+    pub(crate) fn finish(mut self) -> Option<Deduction> {
+        if self.placements.is_empty() && self.eliminations.is_empty() {
+            return None;
+        }
+        self.eliminations.sort();
+        Some(self)
+    }
+    /// The rows, columns, regions and extra houses that contain every cell of the pattern, with their
+    /// index
+    pub fn pattern_houses(&self, grid: &Grid) -> Vec<(GroupType, usize)> {
+        if self.pattern_cells.is_empty() {
+            return Vec::new();
+        }
+        let group_types = [
+            GroupType::Rows,
+            GroupType::Columns,
+            GroupType::Regions,
+            GroupType::Extra,
+        ];
+        let mut houses = Vec::new();
+        for group_type in group_types {
+            for (i, group) in grid.unsolved_groups[group_type as usize].iter().enumerate() {
+                let contains_pattern = self
+                    .pattern_cells
+                    .iter()
+                    .all(|&position| group.contains(&grid.index(position)));
+                if contains_pattern {
+                    houses.push((group_type, i));
+                }
+            }
+        }
+        houses
+    }
+    /// Replays the deduction on a board, removing its eliminations and then placing its values
+    pub fn apply(&self, grid: &mut Grid) -> Result<(), SudokuError> {
+        for &(position, value) in self.eliminations.iter() {
+            let index = grid.index(position);
+            grid.cells[index].remove_possibility(value)?;
+        }
+        for &(position, value) in self.placements.iter() {
+            grid.set_cell(position, value)?;
+        }
+        Ok(())
+    }
+}
+impl fmt::Display for Deduction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.technique.name)?;
+        if !self.pattern_cells.is_empty() {
+            write!(f, ": {}", self.pattern_cells.iter().join(", "))?;
+        }
+        if !self.pattern_values.is_empty() {
+            write!(f, " on {}", self.pattern_values.iter().join(", "))?;
+        }
+        if let Some(detail) = &self.detail {
+            write!(f, "\n{}", detail)?;
+        }
+        if !self.placements.is_empty() {
+            let placements = self
+                .placements
+                .iter()
+                .map(|(position, value)| format!("{}={}", position, value))
+                .join(", ");
+            write!(f, "\nPlaced {}", placements)?;
+        }
+        if !self.eliminations.is_empty() {
+            let eliminations = self
+                .eliminations
+                .iter()
+                .map(|(position, value)| format!("{}<>{}", position, value))
+                .join(", ");
+            write!(f, "\nRemoved {}", eliminations)?;
+        }
+        Ok(())
+    }
+}
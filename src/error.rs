@@ -0,0 +1,97 @@
+use crate::Position;
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Everything that can go wrong while building or solving a board
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SudokuError {
+    /// A cell was left without any value it could take while solving
+    Contradiction { position: Position },
+    /// A given can't be placed, because the same value is already given in one of its groups
+    ConflictingGiven { position: Position, value: u8 },
+    /// A cell disagrees with the known answer, found is None if the answer was removed as a
+    /// candidate rather than a different value being placed
+    AnswerMismatch {
+        position: Position,
+        expected: u8,
+        found: Option<u8>,
+    },
+    /// A value outside of the board's range was placed in a cell
+    InvalidValue { position: Position, value: u8 },
+    /// A board was read with a number of rows that can't be split into regions
+    InvalidSize { size: usize },
+    /// A house was added that isn't a different cell of the board for every value
+    InvalidHouse { cells: usize },
+    /// A region was set that isn't one connected shape with a cell for each value, or its number
+    /// is past the last region
+    InvalidRegion { region: usize },
+    /// A cage was added that overlaps another one, or whose different cells can't add up to its
+    /// sum
+    InvalidCage { cells: usize, sum: usize },
+    /// No value can be placed in the remaining cells without breaking the board
+    NoSolution,
+    /// The board couldn't be read, row and col are zero based
+    Parse { row: usize, col: usize, found: char },
+}
+
+impl fmt::Display for SudokuError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SudokuError::Contradiction { position } => {
+                write!(f, "Contradiction: {} has no possible values left", position)
+            }
+            SudokuError::ConflictingGiven { position, value } => {
+                write!(f, "Conflicting given: {} can't be {}", position, value)
+            }
+            SudokuError::AnswerMismatch {
+                position,
+                expected,
+                found: Some(found),
+            } => write!(
+                f,
+                "Answer mismatch: {} should be {}, is {}",
+                position, expected, found
+            ),
+            SudokuError::AnswerMismatch {
+                position,
+                expected,
+                found: None,
+            } => write!(
+                f,
+                "Answer mismatch: {} removed {} as a possibility",
+                position, expected
+            ),
+            SudokuError::InvalidValue { position, value } => {
+                write!(f, "Invalid value: {} can't be set to {}", position, value)
+            }
+            SudokuError::InvalidSize { size } => {
+                write!(f, "Invalid size: a board can't have {} rows", size)
+            }
+            SudokuError::InvalidHouse { cells } => write!(
+                f,
+                "Invalid house: a house needs a different cell of the board for each value, not {} cells",
+                cells
+            ),
+            SudokuError::InvalidRegion { region } => write!(
+                f,
+                "Invalid region: region {} has to be one connected shape with a cell for each value",
+                region + 1
+            ),
+            SudokuError::InvalidCage { cells, sum } => write!(
+                f,
+                "Invalid cage: a cage of {} different cells adding up to {} doesn't fit the board",
+                cells, sum
+            ),
+            SudokuError::NoSolution => write!(f, "No solution: the board can't be completed"),
+            SudokuError::Parse { row, col, found } => write!(
+                f,
+                "Parse error: unexpected '{}' at row {}, column {}",
+                found,
+                row + 1,
+                col + 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SudokuError {}
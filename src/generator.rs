@@ -1,22 +1,32 @@
+use crate::error::SudokuError;
 use crate::grid::Grid;
-use crate::solvers::Solver;
-use crate::{Position, COLS, ROWS};
+use crate::solvers::{needs_uniqueness, BruteForceSearch, Solver};
+use crate::{GroupType, Position};
 use rand::seq::SliceRandom;
 use rand::Rng;
 
-struct SolveDifficulty {
-    difficulty: usize,
-    solver_counts: Vec<usize>,
+// How many placements the search for a second solution tries before leaving it to the solvers
+const SECOND_SOLUTION_PLACEMENTS: usize = 10_000;
+// How many placements filling a board by search tries before starting over with other values
+const FILL_PLACEMENTS: usize = 100_000;
+
+/// How hard a board is to solve with a given, ordered, set of solvers
+pub struct SolveDifficulty {
+    /// Weighted total, where each solver counts 5 times as much as the one before it
+    pub difficulty: usize,
+    /// How many times each solver had to be applied, in the same order as the solvers passed in
+    pub solver_counts: Vec<usize>,
 }
 impl SolveDifficulty {
     fn new(solver_counts: Vec<usize>) -> SolveDifficulty {
         let mut difficulty = 0;
         let mut scalar = 1;
         let exp = 5;
-        //Each rule is 'exp' times harder than the previous
-        for i in 0..solver_counts.len() {
-            difficulty += solver_counts[i] * scalar;
-            scalar *= exp;
+        //Each rule is 'exp' times harder than the previous, long solver lists run past usize, so the
+        //hardest rules all share the largest weight
+        for count in solver_counts.iter() {
+            difficulty = count.saturating_mul(scalar).saturating_add(difficulty);
+            scalar = scalar.saturating_mul(exp);
         }
         SolveDifficulty {
             difficulty,
@@ -25,60 +35,229 @@ impl SolveDifficulty {
     }
 }
 
-///
-///
-///
-pub fn create_board(solvers: Vec<&Solver>) -> Grid {
+/// Creates a new 9x9 board that can be solved using only the given solvers, removing whichever
+/// cells make it the hardest to solve. Solvers are treated as ordered from easiest to hardest
+pub fn create_board(solvers: &[&Solver]) -> Grid {
+    create_board_with_box_size(solvers, 3, 3)
+}
+/// Creates a new board made of regions with the given number of rows and columns, see
+/// [`create_board`]. Boards larger than 9x9 have too many cells to compare every removal, so cells
+/// are removed in a random order instead, as long as the board stays solvable
+pub fn create_board_with_box_size(solvers: &[&Solver], box_rows: usize, box_cols: usize) -> Grid {
+    create_variant_board(solvers, &Grid::with_box_size(box_rows, box_cols))
+}
+/// Creates a new board with the same size, houses and constraints as the given one, e.g. after
+/// [`Grid::add_diagonals`] for Sudoku-X, see [`create_board_with_box_size`]. Only the layout of
+/// the given board is used, not its values
+pub fn create_variant_board(solvers: &[&Solver], layout: &Grid) -> Grid {
     // First, fill in the board randomly until its complete
-    let mut grid: Grid = Grid::new();
+    let mut grid: Grid = layout.empty_copy();
     fill_board(&mut grid);
 
-    prune_hard(&mut grid, solvers);
-    let mut new_grid = grid.copy_grid(true, false);
-    for i in 0..81 {
-        new_grid.cells[i].is_given = new_grid.cells[i].value != 0;
+    if grid.size() > 9 {
+        prune_board(&mut grid, solvers);
+    } else {
+        prune_hard(&mut grid, solvers);
+    }
+    // The pruned board is a subset of a full solution, so copying it can't fail
+    let mut new_grid = grid
+        .copy_grid(true, false)
+        .expect("Pruned board should always be valid");
+    for cell in new_grid.cells.iter_mut() {
+        cell.is_given = cell.value != 0;
     }
     new_grid
 }
+/// Creates an empty board where the regions are random connected shapes, for jigsaw sudoku, see
+/// [`Grid::set_regions`]. The shapes start out as regions with the given number of rows and
+/// columns, then cells are traded between neighboring regions until they're all mixed up
+pub fn create_jigsaw_layout(box_rows: usize, box_cols: usize) -> Grid {
+    loop {
+        let mut grid = Grid::with_box_size(box_rows, box_cols);
+        let size = grid.size();
+        let mut regions = (0..grid.cells.len())
+            .map(|index| grid.region(index))
+            .collect::<Vec<usize>>();
+        for _ in 0..grid.cells.len() * 8 {
+            trade_cells(&mut regions, size);
+        }
+        if grid.set_regions(&regions).is_err() {
+            continue;
+        }
+        // Some shapes can't be filled in at all, so the layout is only kept if a search fills it
+        let Some(search) = BruteForceSearch::new(&grid) else {
+            continue;
+        };
+        let mut solutions = Vec::new();
+        search
+            .with_placement_limit(FILL_PLACEMENTS)
+            .search(1, &mut solutions);
+        if !solutions.is_empty() {
+            return grid;
+        }
+    }
+}
+// Moves a random cell on the edge of a region into its neighbor, and a cell of the neighbor
+// touching the region back, as long as both regions are still connected afterwards
+fn trade_cells(regions: &mut [usize], size: usize) {
+    let mut rng = rand::rng();
+    let index = rng.random_range(0..regions.len());
+    let outside = neighbors(index, size)
+        .filter(|&other| regions[other] != regions[index])
+        .collect::<Vec<usize>>();
+    if outside.is_empty() {
+        return;
+    }
+    let (from, to) = (
+        regions[index],
+        regions[outside[rng.random_range(0..outside.len())]],
+    );
+    let touching = (0..regions.len())
+        .filter(|&other| {
+            regions[other] == to
+                && neighbors(other, size)
+                    .any(|neighbor| neighbor != index && regions[neighbor] == from)
+        })
+        .collect::<Vec<usize>>();
+    if touching.is_empty() {
+        return;
+    }
+    let other = touching[rng.random_range(0..touching.len())];
+    regions[index] = to;
+    regions[other] = from;
+    if !Grid::is_connected(size, regions, from) || !Grid::is_connected(size, regions, to) {
+        regions[index] = from;
+        regions[other] = to;
+    }
+}
+// The cells above, below, left and right of the cell, that are on the board
+fn neighbors(index: usize, size: usize) -> impl Iterator<Item = usize> {
+    let (row, col) = (index / size, index % size);
+    [
+        (row > 0).then(|| index - size),
+        (row + 1 < size).then(|| index + size),
+        (col > 0).then(|| index - 1),
+        (col + 1 < size).then(|| index + 1),
+    ]
+    .into_iter()
+    .flatten()
+}
+/// Rates how hard the board is to solve with the given solvers, or None if they can't solve it
+pub fn rate_board(
+    grid: &Grid,
+    solvers: &[&Solver],
+) -> Result<Option<SolveDifficulty>, SudokuError> {
+    let mut new_grid = grid.copy_grid(false, false)?;
+    if needs_uniqueness(solvers) {
+        new_grid.verify_uniqueness();
+    }
+    let rule_counts = count_solver_usage(&mut new_grid, solvers)?;
+    if !new_grid.is_done() {
+        return Ok(None);
+    }
+    Ok(Some(SolveDifficulty::new(rule_counts)))
+}
+// Solves the grid as far as possible, returning how many times each solver was used
+fn count_solver_usage(grid: &mut Grid, solvers: &[&Solver]) -> Result<Vec<usize>, SudokuError> {
+    let mut rule_counts = vec![0usize; solvers.len()];
+    let mut dirty = true;
+    while dirty {
+        dirty = false;
+        for (i, step) in solvers.iter().enumerate() {
+            let func = step.solve_function;
+            dirty |= func(grid)?;
+            if dirty {
+                rule_counts[i] += 1;
+                break;
+            }
+        }
+    }
+    Ok(rule_counts)
+}
 // Fill the board completely, to ensure our board has a solved state
 fn fill_board(grid: &mut Grid) {
     grid.auto_promote = false;
-    let mut replacement = (1..=9).collect::<Vec<u8>>();
-    replacement.shuffle(&mut rand::rng());
-    for i in 0..9 {
-        for j in 0..9 {
-            let value = (j + i * 3 + i / 3) % 9;
-            grid.set_cell(Position::new(i, j), replacement[value]);
-        }
-    }
-    // 123;132;213;231;312;321
-    //    ; --;-- ;-- ;- -;- -
-    //              --; --
-    for group in [ROWS, COLS] {
-        for i in 0..3 {
-            let swap = rand::rng().random_range(0..=5);
-            match swap {
-                1 => swap_group(grid, group, i * 3 + 0, i * 3 + 1),
-                2 => swap_group(grid, group, i * 3 + 0, i * 3 + 2),
-                3 => swap_group(grid, group, i * 3 + 1, i * 3 + 2),
-                4 => {
-                    swap_group(grid, group, i * 3 + 0, i * 3 + 1);
-                    swap_group(grid, group, i * 3 + 1, i * 3 + 2);
-                }
-                5 => {
-                    swap_group(grid, group, i * 3 + 0, i * 3 + 2);
-                    swap_group(grid, group, i * 3 + 1, i * 3 + 2);
-                }
-                _ => {}
-            }
-        }
+    let size = grid.size();
+    let extra_rules = !grid.unsolved_groups[GroupType::Extra as usize].is_empty()
+        || !grid.constraints().is_empty()
+        || grid.has_jigsaw_regions();
+    if extra_rules {
+        fill_by_search(grid);
+    } else {
+        fill_by_pattern(grid);
     }
-    for row in 0..9 {
-        for col in 0..9 {
+    for row in 0..size {
+        for col in 0..size {
             let cell = grid.get_mut_cell_unchecked(Position { row, col });
             cell.answer = Some(cell.value);
         }
     }
+}
+// Variants break the pattern below, e.g. shuffling rows moves the diagonals, so a few random
+// values are placed and the search finishes the board, starting over if they can't all fit
+fn fill_by_search(grid: &mut Grid) {
+    let size = grid.size();
+    loop {
+        let mut seeded = grid.empty_copy();
+        let mut cells = (0..seeded.cells.len()).collect::<Vec<usize>>();
+        cells.shuffle(&mut rand::rng());
+        for &index in cells.iter().take(size) {
+            let possibilities = seeded.cells[index].get_possibilities();
+            if possibilities.is_empty() {
+                break;
+            }
+            let value = possibilities[rand::rng().random_range(0..possibilities.len())];
+            if seeded
+                .set_cell(seeded.position(index), value as u8)
+                .is_err()
+            {
+                break;
+            }
+        }
+        let Some(search) = BruteForceSearch::new(&seeded) else {
+            continue;
+        };
+        let mut solutions = Vec::new();
+        search
+            .with_placement_limit(FILL_PLACEMENTS)
+            .search(1, &mut solutions);
+        let Some(solution) = solutions.pop() else {
+            continue;
+        };
+        for (row, values) in solution.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                grid.set_cell(Position::new(row, col), value)
+                    .expect("Search should only find valid boards");
+            }
+        }
+        return;
+    }
+}
+fn fill_by_pattern(grid: &mut Grid) {
+    let size = grid.size();
+    let (box_rows, box_cols) = grid.box_size();
+    let mut replacement = (1..=size as u8).collect::<Vec<u8>>();
+    replacement.shuffle(&mut rand::rng());
+    for i in 0..size {
+        for j in 0..size {
+            // Each row in a band is shifted by a region's width, and each band by one more
+            let value = (j + box_cols * (i % box_rows) + i / box_rows) % size;
+            grid.set_cell(Position::new(i, j), replacement[value])
+                .expect("Base pattern should always be a valid board");
+        }
+    }
+    // Shuffle the rows within each band, and the columns within each stack
+    for (group_type, span) in [(GroupType::Rows, box_rows), (GroupType::Columns, box_cols)] {
+        let group = grid.unsolved_groups[group_type as usize].clone();
+        for start in (0..size).step_by(span) {
+            for i in (1..span).rev() {
+                let j = rand::rng().random_range(0..=i);
+                if i != j {
+                    swap_group(grid, &group, start + i, start + j);
+                }
+            }
+        }
+    }
     /*
     let mut unset_cells = (0..81).collect::<Vec<usize>>();
 
@@ -102,44 +281,34 @@ fn fill_board(grid: &mut Grid) {
         solve(grid);
     }*/
 }
-fn swap_group(
-    grid: &mut Grid,
-    group: &[[usize; 9]; 9],
-    group_index_1: usize,
-    group_index_2: usize,
-) {
-    for i in 0..9 {
-        let was = grid.cells[group[group_index_1][i]].value;
-        grid.cells[group[group_index_1][i]].value = grid.cells[group[group_index_2][i]].value;
-        grid.cells[group[group_index_2][i]].value = was;
+fn swap_group(grid: &mut Grid, group: &[Vec<usize>], group_index_1: usize, group_index_2: usize) {
+    for (&index_1, &index_2) in group[group_index_1].iter().zip(group[group_index_2].iter()) {
+        let was = grid.cells[index_1].value;
+        grid.cells[index_1].value = grid.cells[index_2].value;
+        grid.cells[index_2].value = was;
     }
 }
 // Randomly tries removing cells, and then checking to make sure the board is still solvable,
 // until no more cells can be removed
-#[allow(unused)]
-fn prune_board(grid: &mut Grid, solvers: Vec<&Solver>) {
-    let mut set_cells = (0..81).collect::<Vec<usize>>();
+fn prune_board(grid: &mut Grid, solvers: &[&Solver]) {
+    let mut set_cells = (0..grid.cells.len()).collect::<Vec<usize>>();
     set_cells.shuffle(&mut rand::rng());
     grid.auto_promote = false;
-    while set_cells.len() > 0 {
-        let cell_index = set_cells.pop().unwrap();
-        let pos = Position::from_index(cell_index);
+    while let Some(cell_index) = set_cells.pop() {
+        let pos = grid.position(cell_index);
         let old_value = grid.cells[cell_index].value;
         //grid.unset_cell(pos);
-        let solve = try_solve(grid, &solvers, pos);
-        if solve.is_none() {
+        let solve = try_solve(grid, solvers, pos);
+        let Some(solve) = solve else {
             //println!("Failed to remove at: {},{}", pos.row, pos.col);
             grid.cells[cell_index].value = old_value;
-        } else if solve.unwrap() != old_value {
+            continue;
+        };
+        if solve != old_value {
             // This will only be hit if by removing this cell's value, the only value it found was
             // different from what it is now, which shouldn't be possible
             println!("{}", grid);
-            println!(
-                "{:?}: should be {}, found {}",
-                pos,
-                old_value,
-                solve.unwrap()
-            );
+            println!("{:?}: should be {}, found {}", pos, old_value, solve);
             panic!("PUZZLE BROKE WHILE DESTRUCTING")
         } else {
             grid.unset_cell(pos);
@@ -150,22 +319,20 @@ fn prune_board(grid: &mut Grid, solvers: Vec<&Solver>) {
 }
 // Removes whichever cell will make the board the hardest, given the rules it is allowed to use
 // Solvers array is treated as ordered from easiest to hardest
-fn prune_hard(grid: &mut Grid, solvers: Vec<&Solver>) {
-    let mut set_cells = (0..81).collect::<Vec<usize>>();
+fn prune_hard(grid: &mut Grid, solvers: &[&Solver]) {
+    let mut set_cells = (0..grid.cells.len()).collect::<Vec<usize>>();
     grid.auto_promote = false;
-    let mut rule_counts = vec![0; solvers.len()];
-    while set_cells.len() > 0 {
+    while !set_cells.is_empty() {
         let mut best: Vec<usize> = Vec::new();
         let mut best_difficulty = 0usize;
         let mut to_be_removed_indices: Vec<usize> = Vec::new();
         for cell_index in set_cells.iter() {
-            let result = ranked_solve_removal(grid, &solvers, Position::from_index(*cell_index));
-            if result.is_none() {
+            let result = ranked_solve_removal(grid, solvers, grid.position(*cell_index));
+            let Some(result) = result else {
                 to_be_removed_indices.push(*cell_index);
                 continue;
-            }
-            let difficulty = result.as_ref().unwrap().difficulty;
-            rule_counts = result.unwrap().solver_counts;
+            };
+            let difficulty = result.difficulty;
             if difficulty > best_difficulty {
                 best_difficulty = difficulty;
                 best.clear();
@@ -174,29 +341,36 @@ fn prune_hard(grid: &mut Grid, solvers: Vec<&Solver>) {
                 best.push(*cell_index);
             }
         }
-        if best.len() == 0 {
+        if best.is_empty() {
             break;
         }
         let remove_index = best[rand::rng().random_range(0..best.len())];
-        grid.unset_cell(Position::from_index(remove_index));
+        grid.unset_cell(grid.position(remove_index));
         to_be_removed_indices.push(remove_index);
         set_cells.retain(|&x| !to_be_removed_indices.contains(&x));
     }
-    println!("Rules Used:");
-    for i in 0..rule_counts.len() {
-        println!("\t{} {}", rule_counts[i], solvers[i].name);
-    }
 }
 // Copies the board, and then solves the copy with the given position being unset
 // if the board is solvable, returns the value at position
 // only solves as far as necessary to recover the removed cell
-fn try_solve(grid: &mut Grid, solvers: &Vec<&Solver>, pos: Position) -> Option<u8> {
+fn try_solve(grid: &mut Grid, solvers: &[&Solver], pos: Position) -> Option<u8> {
     // Duplicate the grid, with the given Position being unset
-    let index = pos.get_index();
+    let index = grid.index(pos);
     grid.cells[index].value = 0;
-    let mut new_grid = grid.copy_grid(false, false);
+    let new_grid = grid.copy_grid(false, false);
     let answer = grid.cells[index].answer?;
     grid.cells[index].value = answer;
+    let mut new_grid = new_grid.ok()?;
+    // Without a unique solution no solver can recover the cell. A short search usually finds a
+    // second solution much faster than running out of solvers does, and if it doesn't, the solvers
+    // only recover the cell when the board is still unique anyway
+    if needs_uniqueness(solvers) {
+        if !new_grid.verify_uniqueness() {
+            return None;
+        }
+    } else if has_second_solution(&new_grid) {
+        return None;
+    }
 
     // run solver until given position is found
     let mut dirty = true;
@@ -204,7 +378,7 @@ fn try_solve(grid: &mut Grid, solvers: &Vec<&Solver>, pos: Position) -> Option<u
         dirty = false;
         for step in solvers {
             let func = step.solve_function;
-            dirty |= func(&mut new_grid);
+            dirty |= func(&mut new_grid).ok()?;
             if dirty {
                 break;
             }
@@ -218,33 +392,36 @@ fn try_solve(grid: &mut Grid, solvers: &Vec<&Solver>, pos: Position) -> Option<u
     }
     None
 }
+// Whether a short search finds two different solutions, a board can still have several if it doesn't
+fn has_second_solution(grid: &Grid) -> bool {
+    let Some(search) = BruteForceSearch::new(grid) else {
+        return true;
+    };
+    let mut solutions = Vec::new();
+    search
+        .with_placement_limit(SECOND_SOLUTION_PLACEMENTS)
+        .search(2, &mut solutions);
+    solutions.len() == 2
+}
 // Same as try_solve, except it returns a solve difficulty
 fn ranked_solve_removal(
     grid: &mut Grid,
-    solvers: &Vec<&Solver>,
+    solvers: &[&Solver],
     pos_to_remove: Position,
 ) -> Option<SolveDifficulty> {
     // Duplicate the grid, with the given Position being unset
-    let index = pos_to_remove.get_index();
+    let index = grid.index(pos_to_remove);
     grid.cells[index].value = 0;
-    let mut new_grid = grid.copy_grid(false, false);
-    let answer = grid.cells[index].answer.unwrap();
+    let new_grid = grid.copy_grid(false, false);
+    let answer = grid.cells[index].answer?;
     grid.cells[index].value = answer;
+    let mut new_grid = new_grid.ok()?;
+    if needs_uniqueness(solvers) {
+        new_grid.verify_uniqueness();
+    }
 
-    let mut rule_counts = vec![0usize; solvers.len()];
     // solve the entire puzzle, storing how many of each solver was used
-    let mut dirty = true;
-    while dirty {
-        dirty = false;
-        for (i, step) in solvers.iter().enumerate() {
-            let func = step.solve_function;
-            dirty |= func(&mut new_grid);
-            if dirty {
-                rule_counts[i] += 1;
-                break;
-            }
-        }
-    }
+    let rule_counts = count_solver_usage(&mut new_grid, solvers).ok()?;
     if new_grid.cells[index].value != answer {
         return None;
     }
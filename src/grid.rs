@@ -1,11 +1,15 @@
 use crate::cell::Cell;
-use crate::{Position, COLS, REGS, ROWS};
+use crate::constraint::{Cage, Constraint};
+use crate::error::SudokuError;
+use crate::solvers::BruteForceSearch;
+use crate::{char_to_value, value_to_char, GroupType, Position, MAX_SIZE};
 use colored::{Color, Colorize};
 use std::cmp::PartialEq;
 use std::fmt;
 use std::fmt::Formatter;
+use std::sync::Arc;
 #[allow(unused)]
-#[derive(PartialEq)]
+#[derive(Copy, Clone, PartialEq)]
 enum BoardState {
     Invalid,
     Constructing,
@@ -13,220 +17,764 @@ enum BoardState {
     Solved,
 }
 
-pub struct Grid {
-    pub cells: [Cell; 81],
-    pub starting_cell_count: usize,
-    pub unsolved_groups: [Vec<Vec<usize>>; 3],
-    pub auto_promote: bool,
-    current_state: BoardState,
+/// A full solution to a board, indexed by row and then column
+pub type Solution = Vec<Vec<u8>>;
+
+// The shape of a board and the rules on it, which don't change once the board is set up, so copies
+// of a grid share it
+#[derive(Clone)]
+pub(crate) struct Layout {
+    // The length of each row, column and region, and how many rows and columns a region spans
+    size: usize,
+    box_rows: usize,
+    box_cols: usize,
+    // The region each cell is in
+    regions: Vec<usize>,
+    // Every other cell that shares a group with each cell
+    peers: Vec<Vec<usize>>,
+    // Every group, across every group type in order
+    pub(crate) groups: Vec<Vec<usize>>,
+    // The groups each cell is in, as indices into `groups`
+    pub(crate) cell_groups: Vec<Vec<usize>>,
+    pub(crate) constraints: Vec<Arc<dyn Constraint>>,
+    // The constraints each cell is in, as indices into `constraints`
+    pub(crate) cell_constraints: Vec<Vec<usize>>,
+    // Whether both main diagonals are houses, for Sudoku-X
+    diagonals: bool,
+    // Whether the regions were replaced with irregular shapes, for jigsaw sudoku
+    jigsaw: bool,
+    // The killer cages, which are also in `constraints`
+    cages: Vec<Cage>,
 }
-impl Clone for Grid {
-    fn clone(&self) -> Grid {
-        let mut new_grid = Self::new();
-        for r in 0..9 {
-            for c in 0..9 {
-                let pos = Position::new(r, c);
-                let value = self.cells[pos.get_index()].value;
-                if value > 0 {
-                    new_grid.set_cell(pos, value);
-                }
+impl Layout {
+    // Works out the groups, peers and constraints of each cell from the groups of every type
+    fn link(&mut self, groups_by_type: &[Vec<Vec<usize>>]) {
+        let cell_count = self.size * self.size;
+        self.groups = groups_by_type.concat();
+        self.cell_groups = vec![Vec::new(); cell_count];
+        for (group_index, group) in self.groups.iter().enumerate() {
+            for &index in group.iter() {
+                self.cell_groups[index].push(group_index);
+            }
+        }
+        self.peers = (0..cell_count)
+            .map(|index| {
+                let mut peers = self.cell_groups[index]
+                    .iter()
+                    .flat_map(|&group| self.groups[group].iter().copied())
+                    .filter(|&other| other != index)
+                    .collect::<Vec<usize>>();
+                peers.sort_unstable();
+                peers.dedup();
+                peers
+            })
+            .collect();
+        self.cell_constraints = vec![Vec::new(); cell_count];
+        for (constraint_index, constraint) in self.constraints.iter().enumerate() {
+            for &index in constraint.cells().iter() {
+                self.cell_constraints[index].push(constraint_index);
             }
         }
-        new_grid
     }
 }
 
+#[derive(Clone)]
+pub struct Grid {
+    pub cells: Vec<Cell>,
+    pub starting_cell_count: usize,
+    pub unsolved_groups: Arc<[Vec<Vec<usize>>; 4]>,
+    pub auto_promote: bool,
+    pub(crate) layout: Arc<Layout>,
+    current_state: BoardState,
+    // Whether the board's values are known to only have one solution, see verify_uniqueness
+    verified_unique: bool,
+}
+
 // region Getters
 #[allow(unused)]
 impl Grid {
     pub fn get_cell(&self, pos: Position) -> Option<&Cell> {
-        if pos.row > 8 || pos.col > 8 {
+        if pos.row >= self.size() || pos.col >= self.size() {
             return None;
         }
-        Some(&self.cells[pos.get_index()])
+        Some(&self.cells[self.index(pos)])
     }
     pub fn get_mut_cell(&mut self, pos: Position) -> Option<&mut Cell> {
-        if pos.row > 8 || pos.col > 8 {
+        if pos.row >= self.size() || pos.col >= self.size() {
             return None;
         }
-        Some(&mut self.cells[pos.get_index()])
+        let index = self.index(pos);
+        Some(&mut self.cells[index])
     }
     pub fn get_cell_unchecked(&self, pos: Position) -> &Cell {
-        &self.cells[pos.get_index()]
+        &self.cells[self.index(pos)]
     }
 
     #[allow(dead_code)]
     pub fn get_mut_cell_unchecked(&mut self, pos: Position) -> &mut Cell {
-        &mut self.cells[pos.get_index()]
+        let index = self.index(pos);
+        &mut self.cells[index]
     }
     #[allow(dead_code)]
     pub fn get_percent(&self) -> f32 {
         let mut count_cells = 0;
-        for cell in self.cells {
+        for cell in self.cells.iter() {
             if cell.value > 0 {
                 count_cells += 1;
             }
         }
         let added = count_cells - self.starting_cell_count;
-        let needed = 81 - self.starting_cell_count;
+        let needed = self.cells.len() - self.starting_cell_count;
         (added as f32) / (needed as f32)
     }
+    /// The length of each row, column and region, which is also the largest value
+    pub fn size(&self) -> usize {
+        self.layout.size
+    }
+    /// How many rows and columns each region spans, or would span without jigsaw regions, which
+    /// also sets how a cell's candidates are laid out when the board is shown
+    pub fn box_size(&self) -> (usize, usize) {
+        (self.layout.box_rows, self.layout.box_cols)
+    }
+    /// A candidate bitset with every value of the board set
+    pub fn all_candidates(&self) -> u32 {
+        (1 << self.size()) - 1
+    }
+    pub fn position(&self, index: usize) -> Position {
+        Position {
+            row: index / self.size(),
+            col: index % self.size(),
+        }
+    }
+    pub fn index(&self, pos: Position) -> usize {
+        pos.row * self.size() + pos.col
+    }
+    /// The index of the region the cell is in, matching the order of the region groups
+    pub fn region(&self, index: usize) -> usize {
+        self.layout.regions[index]
+    }
+    /// Every other cell that shares a group with the cell
+    pub fn peers(&self, index: usize) -> &[usize] {
+        &self.layout.peers[index]
+    }
+    /// Whether the regions are irregular shapes, see [`Grid::set_regions`]
+    pub fn has_jigsaw_regions(&self) -> bool {
+        self.layout.jigsaw
+    }
+    /// Whether both main diagonals are houses, see [`Grid::add_diagonals`]
+    pub fn has_diagonals(&self) -> bool {
+        self.layout.diagonals
+    }
+    /// Whether the cell is on one of the diagonals of a Sudoku-X board
+    pub fn on_diagonal(&self, index: usize) -> bool {
+        let pos = self.position(index);
+        self.has_diagonals() && (pos.row == pos.col || pos.row + pos.col == self.size() - 1)
+    }
+    /// The rules on the board that aren't houses, see [`Constraint`]
+    pub fn constraints(&self) -> &[Arc<dyn Constraint>] {
+        &self.layout.constraints
+    }
+    /// The killer cages on the board, see [`Grid::add_cage`]
+    pub fn cages(&self) -> &[Cage] {
+        &self.layout.cages
+    }
 }
 // endregion Getters
 // region Init
 impl Grid {
+    /// Reads a board with one line per row, using 1-9 and then A-P for values, and 0, '.' or ' '
+    /// for unknown cells. The number of lines sets the size of the board, and the regions are as
+    /// close to square as that size allows, with more columns than rows, e.g. 2x3 for a 6x6 board.
+    /// A first line of just X marks a Sudoku-X board, where both main diagonals are houses too.
+    /// For jigsaw sudoku, the board is followed by an empty line and then the same number of lines
+    /// again, with a character per cell naming its region, e.g. the letters A-I. Killer cages can
+    /// also follow the empty line, one per line as the sum and then the cells, e.g. "12: r1c1 r1c2".
+    /// The answer, if given, must have a value for every cell of the board
     pub fn from_string(
         input: &str,
-        answer: Option<[[u8; 9]; 9]>,
+        answer: Option<Solution>,
         auto_promote: bool,
-    ) -> Option<Grid> {
-        let mut grid = Grid::new();
+    ) -> Result<Grid, SudokuError> {
+        let mut lines = input.lines().collect::<Vec<&str>>();
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        let diagonals = lines
+            .first()
+            .is_some_and(|line| line.trim().eq_ignore_ascii_case("x"));
+        if diagonals {
+            lines.remove(0);
+        }
+        let (cage_lines, region_lines): (Vec<&str>, Vec<&str>) =
+            match lines.iter().position(|line| line.is_empty()) {
+                Some(split) => lines
+                    .split_off(split)
+                    .into_iter()
+                    .filter(|line| !line.is_empty())
+                    .partition(|line| line.contains(':')),
+                None => Default::default(),
+            };
+        let size = lines.len();
+        let (box_rows, box_cols) =
+            Grid::box_shape(size).ok_or(SudokuError::InvalidSize { size })?;
+        let mut grid = Grid::with_box_size(box_rows, box_cols);
+        if !region_lines.is_empty() {
+            grid.set_regions(&Grid::parse_regions(&region_lines, size)?)?;
+        }
+        if diagonals {
+            grid.add_diagonals()?;
+        }
+        for (row, line) in cage_lines.iter().enumerate() {
+            let (sum, cells) = Grid::parse_cage(line, row)?;
+            grid.add_cage(&cells, sum)?;
+        }
         grid.auto_promote = auto_promote;
         grid.current_state = BoardState::Constructing;
         let mut starting_cell_count = 0;
-        'rowloop: for (row, line) in input.lines().enumerate() {
+        for (row, line) in lines.iter().enumerate() {
             for (col, cell_value) in line.chars().enumerate() {
-                if cell_value == ' ' || cell_value == '0' {
+                if cell_value == ' ' || cell_value == '0' || cell_value == '.' {
                     continue;
                 }
-                starting_cell_count += 1;
-                let digit = cell_value.to_digit(10);
-                if digit.is_none() {
-                    grid.current_state = BoardState::Invalid;
-                    break 'rowloop;
+                let parse_error = SudokuError::Parse {
+                    row,
+                    col,
+                    found: cell_value,
+                };
+                if col >= size {
+                    return Err(parse_error);
                 }
-                let digit = digit.unwrap();
-                grid.set_cell(Position { row, col }, digit as u8);
+                let Some(value) = char_to_value(cell_value) else {
+                    return Err(parse_error);
+                };
+                starting_cell_count += 1;
+                grid.set_cell(Position { row, col }, value)?;
                 grid.get_mut_cell_unchecked(Position { row, col }).is_given = true;
             }
         }
-        if answer.is_some() {
-            let answer = answer.unwrap();
-            for row in 0..answer.len() {
-                for col in 0..answer[row].len() {
-                    grid.get_mut_cell_unchecked(Position { row, col }).answer =
-                        Some(answer[row][col]);
+        if let Some(answer) = answer {
+            if answer.len() != size {
+                return Err(SudokuError::InvalidSize { size: answer.len() });
+            }
+            for (row, answer_row) in answer.iter().enumerate() {
+                if answer_row.len() != size {
+                    return Err(SudokuError::InvalidSize {
+                        size: answer_row.len(),
+                    });
+                }
+                for (col, &value) in answer_row.iter().enumerate() {
+                    if value == 0 || value as usize > size {
+                        return Err(SudokuError::InvalidValue {
+                            position: Position { row, col },
+                            value,
+                        });
+                    }
+                    grid.get_mut_cell_unchecked(Position { row, col }).answer = Some(value);
                 }
             }
         }
         grid.starting_cell_count = starting_cell_count;
-        if grid.current_state == BoardState::Invalid {
+        grid.current_state = BoardState::Solving;
+        Ok(grid)
+    }
+    // Reads a region layout, numbering the regions in the order their names first show up
+    fn parse_regions(lines: &[&str], size: usize) -> Result<Vec<usize>, SudokuError> {
+        let mut names = Vec::with_capacity(size);
+        let mut regions = Vec::with_capacity(size * size);
+        for row in 0..size {
+            let mut chars = lines.get(row).map_or("", |line| line).chars();
+            for col in 0..=size {
+                let name = chars.next();
+                let parse_error = SudokuError::Parse {
+                    row,
+                    col,
+                    found: name.unwrap_or(' '),
+                };
+                let name = match name {
+                    // Anything past the last column has to be left off
+                    _ if col == size => match name {
+                        Some(_) => return Err(parse_error),
+                        None => break,
+                    },
+                    None | Some(' ') => return Err(parse_error),
+                    Some(name) => name,
+                };
+                let region = match names.iter().position(|&other| other == name) {
+                    Some(region) => region,
+                    None if names.len() < size => {
+                        names.push(name);
+                        names.len() - 1
+                    }
+                    None => return Err(parse_error),
+                };
+                regions.push(region);
+            }
+        }
+        if lines.len() > size {
+            return Err(SudokuError::InvalidSize { size: lines.len() });
+        }
+        Ok(regions)
+    }
+    // Reads a cage written as its sum and then its cells, where the row of an error is the cage's
+    // line rather than a row of the board
+    fn parse_cage(line: &str, row: usize) -> Result<(usize, Vec<Position>), SudokuError> {
+        let chars = line.chars().collect::<Vec<char>>();
+        let parse_error = |col: usize| SudokuError::Parse {
+            row,
+            col,
+            found: chars.get(col).copied().unwrap_or(' '),
+        };
+        let split = chars
+            .iter()
+            .position(|&c| c == ':')
+            .ok_or_else(|| parse_error(0))?;
+        // Each word of the line, with the column of its first character
+        let mut words: Vec<(usize, String)> = Vec::new();
+        let mut in_word = false;
+        for (col, &c) in chars.iter().enumerate() {
+            if c.is_whitespace() || c == ',' || col == split {
+                in_word = false;
+            } else if in_word {
+                words.last_mut().expect("A word was started").1.push(c);
+            } else {
+                words.push((col, c.to_string()));
+                in_word = true;
+            }
+        }
+        let (sum, cells) = words.split_at(words.partition_point(|&(col, _)| col < split));
+        let sum = match sum {
+            [(_, sum)] => sum.parse::<usize>().ok(),
+            _ => None,
+        }
+        .ok_or_else(|| parse_error(sum.first().map_or(split, |&(col, _)| col)))?;
+        let mut positions = Vec::new();
+        for (col, cell) in cells.iter() {
+            let position = cell
+                .strip_prefix(['r', 'R'])
+                .and_then(|rest| rest.split_once(['c', 'C']))
+                .and_then(|(row, col)| {
+                    Some((row.parse::<usize>().ok()?, col.parse::<usize>().ok()?))
+                })
+                .filter(|&(row, col)| {
+                    (1..=MAX_SIZE).contains(&row) && (1..=MAX_SIZE).contains(&col)
+                })
+                .ok_or_else(|| parse_error(*col))?;
+            positions.push(Position::new(position.0 - 1, position.1 - 1));
+        }
+        Ok((sum, positions))
+    }
+    /// The rows and columns each region spans for a board of the given size, as close to square as
+    /// possible with more columns than rows, or None if the size can't be split into regions
+    pub fn box_shape(size: usize) -> Option<(usize, usize)> {
+        if size > MAX_SIZE {
             return None;
         }
-        grid.current_state = BoardState::Solving;
-        Some(grid)
+        let box_rows = (2..=size)
+            .take_while(|rows| rows * rows <= size)
+            .filter(|&rows| size.is_multiple_of(rows))
+            .last()?;
+        Some((box_rows, size / box_rows))
     }
-    pub fn copy_grid(&self, copy_answer: bool, auto_promote: bool) -> Grid {
-        let mut new_grid = Self::new();
+    /// Creates a new grid with the same values as this one, recalculating all candidates
+    pub fn copy_grid(&self, copy_answer: bool, auto_promote: bool) -> Result<Grid, SudokuError> {
+        let mut new_grid = self.empty_copy();
         new_grid.auto_promote = auto_promote;
-        for r in 0..9 {
-            for c in 0..9 {
-                let pos = Position::new(r, c);
-                let index = pos.get_index();
-                let value = self.cells[index].value;
-                if value > 0 {
-                    new_grid.set_cell(pos, value);
-                }
-                if copy_answer {
-                    new_grid.cells[index].answer = self.cells[index].answer;
-                }
+        for index in 0..self.cells.len() {
+            let value = self.cells[index].value;
+            if value > 0 {
+                new_grid.set_cell(self.position(index), value)?;
+            }
+            if copy_answer {
+                new_grid.cells[index].answer = self.cells[index].answer;
             }
         }
-        new_grid
+        new_grid.current_state = BoardState::Solving;
+        // The copy has the same values, so it has the same solutions
+        new_grid.verified_unique = self.verified_unique;
+        Ok(new_grid)
     }
 
+    /// Creates an empty 9x9 grid
     pub fn new() -> Grid {
+        Self::with_box_size(3, 3)
+    }
+    /// Creates an empty grid made of regions with the given number of rows and columns, the board
+    /// is as wide as a region has cells, e.g. 2x3 regions make a 6x6 board
+    pub fn with_box_size(box_rows: usize, box_cols: usize) -> Grid {
+        let size = box_rows * box_cols;
+        assert!(size <= MAX_SIZE, "Board is too large");
         let cell = Cell {
-            candidates: 0x1FF,
+            candidates: (1 << size) - 1,
             value: 0,
             answer: None,
             is_given: false,
             is_dirty: false,
+            position: Position { row: 0, col: 0 },
+        };
+        let cells = (0..size * size)
+            .map(|index| Cell {
+                position: Position {
+                    row: index / size,
+                    col: index % size,
+                },
+                ..cell
+            })
+            .collect();
+        let mut rows = vec![Vec::with_capacity(size); size];
+        let mut cols = vec![Vec::with_capacity(size); size];
+        let mut regs = vec![Vec::with_capacity(size); size];
+        let mut regions = Vec::with_capacity(size * size);
+        for index in 0..size * size {
+            let (row, col) = (index / size, index % size);
+            // Regions are numbered left to right, then top to bottom
+            let region = row / box_rows * box_rows + col / box_cols;
+            rows[row].push(index);
+            cols[col].push(index);
+            regs[region].push(index);
+            regions.push(region);
+        }
+        let unsolved_groups = [rows, cols, regs, Vec::new()];
+        let mut layout = Layout {
+            size,
+            box_rows,
+            box_cols,
+            regions,
+            peers: Vec::new(),
+            groups: Vec::new(),
+            cell_groups: Vec::new(),
+            constraints: Vec::new(),
+            cell_constraints: Vec::new(),
+            diagonals: false,
+            jigsaw: false,
+            cages: Vec::new(),
         };
-        let cells = [cell; 81];
-        let rows = ROWS.clone().map(|x| x.to_vec()).to_vec();
-        let cols = COLS.clone().map(|x| x.to_vec()).to_vec();
-        let regs = REGS.clone().map(|x| x.to_vec()).to_vec();
-        let unsolved_groups = [rows, cols, regs];
+        layout.link(&unsolved_groups);
         Grid {
             cells,
             starting_cell_count: 0,
-            unsolved_groups,
+            unsolved_groups: Arc::new(unsolved_groups),
+            auto_promote: true,
+            layout: Arc::new(layout),
+            current_state: BoardState::Constructing,
+            verified_unique: false,
+        }
+    }
+    // An empty grid with the same layout as this one
+    pub(crate) fn empty_copy(&self) -> Grid {
+        let all_candidates = self.all_candidates();
+        Grid {
+            cells: self
+                .cells
+                .iter()
+                .map(|cell| Cell {
+                    candidates: all_candidates,
+                    value: 0,
+                    answer: None,
+                    is_given: false,
+                    is_dirty: false,
+                    position: cell.position,
+                })
+                .collect(),
+            starting_cell_count: 0,
+            unsolved_groups: Arc::clone(&self.unsolved_groups),
             auto_promote: true,
+            layout: Arc::clone(&self.layout),
             current_state: BoardState::Constructing,
+            verified_unique: false,
+        }
+    }
+}
+// region Variants
+impl Grid {
+    /// Adds a house, a group of cells that has to hold every value once, on top of the rows,
+    /// columns and regions. Every technique that works on groups uses it straight away, and values
+    /// already on the board are removed as candidates from the rest of the house
+    pub fn add_house(&mut self, cells: &[Position]) -> Result<(), SudokuError> {
+        let invalid = SudokuError::InvalidHouse { cells: cells.len() };
+        let size = self.size();
+        if cells.len() != size || cells.iter().any(|pos| pos.row >= size || pos.col >= size) {
+            return Err(invalid);
+        }
+        let mut house = cells
+            .iter()
+            .map(|&pos| self.index(pos))
+            .collect::<Vec<usize>>();
+        house.sort_unstable();
+        house.dedup();
+        if house.len() != size {
+            return Err(invalid);
+        }
+        let mut seen = 0u32;
+        for &index in house.iter() {
+            let value = self.cells[index].value;
+            if value == 0 {
+                continue;
+            }
+            if seen & (1 << (value - 1)) != 0 {
+                return Err(SudokuError::ConflictingGiven {
+                    position: self.position(index),
+                    value,
+                });
+            }
+            seen |= 1 << (value - 1);
         }
+        Arc::make_mut(&mut self.unsolved_groups)[GroupType::Extra as usize].push(house.clone());
+        Arc::make_mut(&mut self.layout).link(&self.unsolved_groups[..]);
+        // Fewer solutions can only be left with an extra house, but it could leave none
+        self.verified_unique = false;
+        for index in house {
+            if self.cells[index].value != 0 {
+                self.remove_seen_candidates(self.position(index))?;
+            }
+        }
+        Ok(())
+    }
+    /// Makes both main diagonals houses, so they have to hold every value once too, for Sudoku-X
+    pub fn add_diagonals(&mut self) -> Result<(), SudokuError> {
+        let size = self.size();
+        let diagonal = (0..size)
+            .map(|i| Position::new(i, i))
+            .collect::<Vec<Position>>();
+        let anti_diagonal = (0..size)
+            .map(|i| Position::new(i, size - 1 - i))
+            .collect::<Vec<Position>>();
+        self.add_house(&diagonal)?;
+        self.add_house(&anti_diagonal)?;
+        Arc::make_mut(&mut self.layout).diagonals = true;
+        Ok(())
+    }
+    /// Replaces the regions with the given ones, for jigsaw sudoku, where the region of each cell is
+    /// given in the same order as [`Grid::cells`]. Every region has to be a connected shape, with a
+    /// cell for each value. Candidates are worked out again for the new regions
+    pub fn set_regions(&mut self, regions: &[usize]) -> Result<(), SudokuError> {
+        let size = self.size();
+        let mut region_cells = vec![Vec::with_capacity(size); size];
+        if regions.len() != self.cells.len() {
+            // Read as rows of the board, either there are the wrong number of rows, or the last row
+            // is cut short, like a wrongly sized answer
+            let found = match regions.len() % size {
+                0 => regions.len() / size,
+                short_row => short_row,
+            };
+            return Err(SudokuError::InvalidSize { size: found });
+        }
+        for (index, &region) in regions.iter().enumerate() {
+            if region >= size {
+                return Err(SudokuError::InvalidRegion { region });
+            }
+            region_cells[region].push(index);
+        }
+        for (region, cells) in region_cells.iter().enumerate() {
+            if cells.len() != size || !Grid::is_connected(size, regions, region) {
+                return Err(SudokuError::InvalidRegion { region });
+            }
+        }
+        let mut groups = (*self.unsolved_groups).clone();
+        groups[GroupType::Regions as usize] = region_cells;
+        let mut layout = (*self.layout).clone();
+        layout.regions = regions.to_vec();
+        layout.jigsaw = true;
+        layout.link(&groups);
+        // Candidates ruled out by the old regions might be allowed now, so the values are placed
+        // again from scratch
+        let mut grid = Grid {
+            unsolved_groups: Arc::new(groups),
+            layout: Arc::new(layout),
+            ..self.empty_copy()
+        };
+        grid.auto_promote = self.auto_promote;
+        for (index, cell) in self.cells.iter().enumerate() {
+            if cell.value != 0 {
+                grid.set_cell(self.position(index), cell.value)?;
+            }
+            grid.cells[index].is_given = cell.is_given;
+            grid.cells[index].answer = cell.answer;
+        }
+        grid.starting_cell_count = self.starting_cell_count;
+        grid.current_state = self.current_state;
+        *self = grid;
+        Ok(())
+    }
+    /// Whether the cells of the region, given for every cell as in [`Grid::set_regions`], are one
+    /// shape, where each cell can be reached from the others through its neighbors in the region
+    pub fn is_connected(size: usize, regions: &[usize], region: usize) -> bool {
+        let Some(start) = regions.iter().position(|&other| other == region) else {
+            return false;
+        };
+        let mut reached = vec![start];
+        let mut next = 0;
+        while next < reached.len() {
+            let index = reached[next];
+            next += 1;
+            let (row, col) = (index / size, index % size);
+            let neighbors = [
+                (row > 0).then(|| index - size),
+                (row + 1 < size).then(|| index + size),
+                (col > 0).then(|| index - 1),
+                (col + 1 < size).then(|| index + 1),
+            ];
+            for neighbor in neighbors.into_iter().flatten() {
+                if regions[neighbor] == region && !reached.contains(&neighbor) {
+                    reached.push(neighbor);
+                }
+            }
+        }
+        reached.len() == regions.iter().filter(|&&other| other == region).count()
+    }
+    /// Adds a killer cage, cells that can't repeat a value and have to add up to the sum, see
+    /// [`Cage`]. Cages can't overlap each other
+    pub fn add_cage(&mut self, cells: &[Position], sum: usize) -> Result<(), SudokuError> {
+        let invalid = SudokuError::InvalidCage {
+            cells: cells.len(),
+            sum,
+        };
+        let size = self.size();
+        if cells.is_empty()
+            || cells.len() > size
+            || cells.iter().any(|pos| pos.row >= size || pos.col >= size)
+        {
+            return Err(invalid);
+        }
+        let mut indices = cells
+            .iter()
+            .map(|&pos| self.index(pos))
+            .collect::<Vec<usize>>();
+        indices.sort_unstable();
+        indices.dedup();
+        let overlaps = self.cages().iter().any(|cage| {
+            cage.cells()
+                .iter()
+                .any(|index| indices.binary_search(index).is_ok())
+        });
+        if indices.len() != cells.len() || overlaps {
+            return Err(invalid);
+        }
+        let cage = Cage::new(self, indices, sum);
+        // Checks that the sum can be made at all, along with the values already on the board
+        let values = self
+            .cells
+            .iter()
+            .map(|cell| cell.value)
+            .collect::<Vec<u8>>();
+        if !cage.allows(&values) {
+            return Err(invalid);
+        }
+        Arc::make_mut(&mut self.layout).cages.push(cage.clone());
+        self.add_constraint(cage);
+        Ok(())
+    }
+    /// Adds a rule on the board that isn't a house, see [`Constraint`]
+    pub fn add_constraint(&mut self, constraint: impl Constraint + 'static) {
+        let layout = Arc::make_mut(&mut self.layout);
+        layout.constraints.push(Arc::new(constraint));
+        layout.link(&self.unsolved_groups[..]);
+        self.verified_unique = false;
+    }
+}
+// endregion Variants
+impl Default for Grid {
+    fn default() -> Self {
+        Self::new()
     }
 }
 // endregion Init
 
 impl Grid {
     pub fn is_done(&self) -> bool {
-        for cell in self.cells {
+        for cell in self.cells.iter() {
             if cell.value == 0 {
                 return false;
             }
         }
         true
     }
-    pub fn set_cell(&mut self, pos: Position, value: u8) {
-        let cell = &mut self.cells[pos.get_index()];
+    /// Counts how many solutions the board has, stopping once `limit` have been found
+    pub fn count_solutions(&self, limit: usize) -> usize {
+        self.find_solutions(limit).len()
+    }
+    /// Checks whether the board has exactly one solution, and remembers the result, solvers that
+    /// rely on the board being unique won't do anything until this has returned true
+    pub fn verify_uniqueness(&mut self) -> bool {
+        self.verified_unique = self.count_solutions(2) == 1;
+        self.verified_unique
+    }
+    pub fn is_verified_unique(&self) -> bool {
+        self.verified_unique
+    }
+    /// Finds up to `limit` different solutions to the board by searching every possibility
+    pub fn find_solutions(&self, limit: usize) -> Vec<Solution> {
+        let mut solutions = Vec::new();
+        if let Some(mut search) = BruteForceSearch::new(self) {
+            search.search(limit, &mut solutions);
+        }
+        solutions
+    }
+    pub fn set_cell(&mut self, pos: Position, value: u8) -> Result<(), SudokuError> {
+        if value == 0 || value as usize > self.size() {
+            return Err(SudokuError::InvalidValue {
+                position: pos,
+                value,
+            });
+        }
+        let conflict = if self.current_state == BoardState::Constructing {
+            SudokuError::ConflictingGiven {
+                position: pos,
+                value,
+            }
+        } else {
+            SudokuError::Contradiction { position: pos }
+        };
+        let index = self.index(pos);
+        let cell = &mut self.cells[index];
         if cell.value != 0 {
             if cell.value != value {
-                if self.current_state == BoardState::Solving {
-                    panic!("Overwriting existing cell!");
-                } else if self.current_state == BoardState::Constructing {
-                    self.current_state = BoardState::Invalid;
-                }
+                return Err(conflict);
             }
-            return;
+            return Ok(());
         }
         if cell.candidates & (1 << (value - 1)) == 0 {
-            if self.current_state == BoardState::Solving {
-                // If this value isn't a possible value, panic
-                panic!("INVALID SET CELL");
-            } else if self.current_state == BoardState::Constructing {
-                self.current_state = BoardState::Invalid;
-            }
+            // If this value isn't a possible value, the board is broken
+            return Err(conflict);
         }
-        cell.set_value(value);
-        self.remove_seen_candidates(pos);
-        //self.remove_unsolved_cell(pos.row * 9 + pos.col)
+        cell.set_value(value)?;
+        self.remove_seen_candidates(pos)
+        //self.remove_unsolved_cell(index)
+    }
+    /// Every group the position is in, its row, column and region, and any houses added to it
+    pub fn get_cell_groups(&self, pos: Position) -> Vec<&[usize]> {
+        self.layout.cell_groups[self.index(pos)]
+            .iter()
+            .map(|&group| self.layout.groups[group].as_slice())
+            .collect()
     }
-    pub fn get_cell_groups(pos: Position) -> Vec<&'static [usize; 9]> {
-        let region = Position::region(&pos).0;
-        vec![&COLS[pos.col], &ROWS[pos.row], &REGS[region]]
+    /// Whether two different cells share a group
+    pub fn sees(&self, a: usize, b: usize) -> bool {
+        self.layout.peers[a].binary_search(&b).is_ok()
     }
     pub fn unset_cell(&mut self, pos: Position) {
-        let cell = self.get_mut_cell(pos);
-        if cell.is_none() {
+        let all_candidates = self.all_candidates();
+        let Some(cell) = self.get_mut_cell(pos) else {
             return;
-        }
-        let cell = cell.unwrap();
+        };
         cell.value = 0;
-        cell.candidates = 0b111_111_111;
+        cell.candidates = all_candidates;
         cell.is_dirty = true;
-        self.force_update_candidates(pos);
+        // Removing a value can let the board have more solutions
+        self.verified_unique = false;
+        let index = self.index(pos);
+        self.force_update_candidates(index);
 
-        let groups = Self::get_cell_groups(pos);
-        for group in groups {
-            for cell in *group {
-                self.force_update_candidates(Position::from_index(cell));
-            }
+        let layout = Arc::clone(&self.layout);
+        for &cell in layout.peers[index].iter() {
+            self.force_update_candidates(cell);
         }
     }
-    fn force_update_candidates(&mut self, pos: Position) {
-        let cell_index = pos.get_index();
-        let mut candidates = 0b111_111_111;
-        let groups = Self::get_cell_groups(pos);
-        for group in groups {
-            for other_index in *group {
-                if other_index == cell_index {
-                    continue;
-                }
-                let cell = self.cells[other_index];
-                if cell.value != 0 {
-                    candidates &= !(1 << (cell.value - 1));
-                }
+    fn force_update_candidates(&mut self, cell_index: usize) {
+        let mut candidates = self.all_candidates();
+        for &other_index in self.peers(cell_index) {
+            let cell = self.cells[other_index];
+            if cell.value != 0 {
+                candidates &= !(1 << (cell.value - 1));
             }
         }
         self.cells[cell_index].candidates = candidates;
@@ -234,31 +782,25 @@ impl Grid {
     #[allow(dead_code)]
     fn remove_unsolved_cell(&mut self, index: usize) {
         // Removes all occurrences of the specified index in Unsolved Groups
-        self.unsolved_groups.iter_mut().for_each(|group| {
-            group
-                .iter_mut()
-                .for_each(|cells| cells.retain(|x| *x != index))
-        });
+        Arc::make_mut(&mut self.unsolved_groups)
+            .iter_mut()
+            .for_each(|group| {
+                group
+                    .iter_mut()
+                    .for_each(|cells| cells.retain(|x| *x != index))
+            });
     }
-    pub fn remove_seen_candidates(&mut self, pos: Position) {
-        let index = pos.row * 9 + pos.col;
+    pub fn remove_seen_candidates(&mut self, pos: Position) -> Result<(), SudokuError> {
+        let index = self.index(pos);
         let value = self.cells[index].value;
-        self.remove_seen_candidate_group(&COLS[pos.col], index, value);
-        self.remove_seen_candidate_group(&ROWS[pos.row], index, value);
-        let region = pos.region();
-        self.remove_seen_candidate_group(&REGS[region.0], index, value);
-    }
-    fn remove_seen_candidate_group(&mut self, group: &[usize; 9], index: usize, value: u8) {
-        for other_index in *group {
-            if other_index != index {
-                self.cells[other_index].remove_possibility(value);
-                if self.auto_promote {
-                    if self.cells[other_index].promote_single_candidate() {
-                        self.remove_seen_candidates(Position::from_index(other_index));
-                    }
-                }
+        let layout = Arc::clone(&self.layout);
+        for &other_index in layout.peers[index].iter() {
+            self.cells[other_index].remove_possibility(value)?;
+            if self.auto_promote && self.cells[other_index].promote_single_candidate()? {
+                self.remove_seen_candidates(self.position(other_index))?;
             }
         }
+        Ok(())
     }
     pub fn clear_dirty(&mut self) {
         for cell in self.cells.iter_mut() {
@@ -269,100 +811,172 @@ impl Grid {
 // region Print
 impl Grid {
     pub fn print_possibilities(&self) {
+        let (box_rows, box_cols) = self.box_size();
+        let size = self.size();
+        let header = (1..=size as u8)
+            .rev()
+            .map(value_to_char)
+            .collect::<String>();
         println!(
             "{}-",
-            "- 987654321 987654321 987654321  "
-                .repeat(3)
-                .color(Color::White)
+            format!("- {} ", header).repeat(size).color(Color::White)
         );
-        for i in 0..9 {
+        for i in 0..size {
             print!("| ");
-            for j in 0..9 {
+            for j in 0..size {
                 print!(
                     "{:?} ",
                     self.get_cell_unchecked(Position { row: i, col: j })
                 );
-                if j % 3 == 2 {
+                if j % box_cols == box_cols - 1 {
                     print!(" | ");
                 }
             }
             println!();
-            if i % 3 == 2 {
-                println!("{}", "-".repeat(100));
+            if i % box_rows == box_rows - 1 {
+                println!("{}", "-".repeat((size + 2) * size));
             }
         }
     }
     pub fn print_board(&self) {
-        println!("{}", "-".repeat(25));
-        for i in 0..9 {
+        let (box_rows, box_cols) = self.box_size();
+        let size = self.size();
+        let width = size * 2 + box_rows * 2 + 1;
+        println!("{}", "-".repeat(width));
+        for i in 0..size {
             print!("| ");
-            for j in 0..9 {
-                print!(
-                    "{:} ",
-                    self.get_cell_unchecked(Position { row: i, col: j }).value
-                );
-                if j % 3 == 2 {
+            for j in 0..size {
+                let value = self.get_cell_unchecked(Position { row: i, col: j }).value;
+                let character = if value == 0 {
+                    '0'
+                } else {
+                    value_to_char(value)
+                };
+                print!("{} ", character);
+                if j % box_cols == box_cols - 1 {
                     print!("| ");
                 }
             }
             println!();
-            if i % 3 == 2 {
-                println!("{}", "-".repeat(25));
+            if i % box_rows == box_rows - 1 {
+                println!("{}", "-".repeat(width));
             }
         }
     }
-}
-impl fmt::Display for Grid {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let mut accumulate = "".to_string();
-        accumulate += "";
-        let mut lines: Vec<String> = Vec::with_capacity(40);
-        lines.push("╔═══════════╦═══════════╦═══════════╗".normal().to_string());
-        for i in 0..9 {
-            if i == 3 || i == 6 {
-                lines.push("╠═══════════╬═══════════╬═══════════╣".normal().to_string());
+    /// Draws the board from the lines of each cell's card, with double lines along the edges of
+    /// regions, single lines along the edges of cages, and dotted lines between other cells. Each
+    /// cage's sum is written on the line above its first cell, as the board is displayed
+    pub fn draw(&self, cards: &[Vec<String>]) -> Vec<String> {
+        let size = self.size() as isize;
+        let (card_rows, card_cols) = self.box_size();
+        let mut cage_of = vec![None; self.cells.len()];
+        for (cage_index, cage) in self.cages().iter().enumerate() {
+            for &index in cage.cells() {
+                cage_of[index] = Some(cage_index);
             }
-            if i % 3 != 0 {
-                lines.push("║┄┄┄ ┄┄┄ ┄┄┄║┄┄┄ ┄┄┄ ┄┄┄║┄┄┄ ┄┄┄ ┄┄┄║".normal().to_string());
-            }
-            let mut cards: Vec<String> = Vec::with_capacity(9);
-            for j in 0..9 {
-                let cell = self.get_cell_unchecked(Position { row: i, col: j });
-                cards.push(cell.get_print_card());
+        }
+        // Outside of the board counts as its own region, so the edge of the board is a border
+        let cell = |row: isize, col: isize| {
+            let on_board = (0..size).contains(&row) && (0..size).contains(&col);
+            on_board.then(|| (row * size + col) as usize)
+        };
+        // How heavy the line between two cells is, 2 between regions, 1 between cages, otherwise 0
+        let weight = |a: Option<usize>, b: Option<usize>| {
+            let (region_a, region_b) = (a.map(|a| self.region(a)), b.map(|b| self.region(b)));
+            let (cage_a, cage_b) = (a.and_then(|a| cage_of[a]), b.and_then(|b| cage_of[b]));
+            match (region_a != region_b, cage_a != cage_b) {
+                (true, _) => 2,
+                (false, true) => 1,
+                (false, false) => 0,
             }
-            let card_rows = cards
-                .iter()
-                .map(|str| str.split('\n').collect::<Vec<&str>>())
-                .collect::<Vec<Vec<&str>>>();
-            let mut rows: Vec<String> = vec!["║".normal().to_string(); 3];
-            for (j, row) in card_rows.iter().enumerate() {
-                rows[0] += row[0];
-                rows[1] += row[1];
-                rows[2] += row[2];
-                if j % 3 == 2 {
-                    rows[0] += "║";
-                    rows[1] += "║";
-                    rows[2] += "║";
+        };
+        let above = |row: isize, col: isize| weight(cell(row - 1, col), cell(row, col));
+        let left = |row: isize, col: isize| weight(cell(row, col - 1), cell(row, col));
+        let mut lines = Vec::with_capacity(size as usize * (card_rows + 1) + 1);
+        for row in 0..=size {
+            let mut border = String::new();
+            for col in 0..=size {
+                border += junction(
+                    left(row - 1, col),
+                    left(row, col),
+                    above(row, col - 1),
+                    above(row, col),
+                );
+                if col == size {
+                    continue;
+                }
+                let edge = ["┄", "─", "═"][above(row, col)];
+                let index = (row * size + col) as usize;
+                // The sum is left off if it's wider than the cell
+                let label = match cage_of.get(index).copied().flatten() {
+                    Some(cage) if self.cages()[cage].cells()[0] == index => {
+                        self.cages()[cage].sum().to_string()
+                    }
+                    _ => String::new(),
+                };
+                let label = if label.len() <= card_cols {
+                    label
                 } else {
-                    rows[0] += "┆";
-                    rows[1] += "┆";
-                    rows[2] += "┆";
+                    String::new()
+                };
+                border += &label;
+                border += &edge.repeat(card_cols - label.len());
+            }
+            lines.push(border);
+            if row == size {
+                break;
+            }
+            let row_cards = &cards[(row * size) as usize..((row + 1) * size) as usize];
+            for card_line in 0..card_rows {
+                let mut line = "║".to_string();
+                for (col, card) in row_cards.iter().enumerate() {
+                    line += &card[card_line];
+                    line += ["┆", "│", "║"][left(row, col as isize + 1)];
                 }
+                lines.push(line);
             }
-            rows[0] = rows[0].trim_end().to_string();
-            rows[1] = rows[1].trim_end().to_string();
-            rows[2] = rows[2].trim_end().to_string();
-            lines.push(rows[0].to_string());
-            lines.push(rows[1].to_string());
-            lines.push(rows[2].to_string());
-        }
-        lines.push("╚═══════════╩═══════════╩═══════════╝".to_string());
-        for i in lines {
-            accumulate += i.as_str();
-            accumulate += "\n";
-        }
-        accumulate = accumulate.trim_end().to_string();
-        write!(f, "{:9}", accumulate)
+        }
+        lines
+    }
+}
+// The pieces joining lines above, below, left and right of a corner of a cell, indexed by which of
+// those have a line, as bits in that order
+const DOUBLE_JUNCTIONS: [&str; 16] = [
+    " ", "║", "║", "║", "═", "╝", "╗", "╣", "═", "╚", "╔", "╠", "═", "╩", "╦", "╬",
+];
+const SINGLE_JUNCTIONS: [&str; 16] = [
+    " ", "│", "│", "│", "─", "┘", "┐", "┤", "─", "└", "┌", "├", "─", "┴", "┬", "┼",
+];
+// The piece where the lines above, below, left and right of a corner of a cell meet, given how
+// heavy each is. Double lines are drawn through single ones, as not every mix has a piece
+fn junction(up: usize, down: usize, left: usize, right: usize) -> &'static str {
+    let heaviest = up.max(down).max(left).max(right);
+    let shape = [up, down, left, right]
+        .iter()
+        .enumerate()
+        .filter(|&(_, &weight)| weight == heaviest && weight != 0)
+        .fold(0, |shape, (bit, _)| shape | 1 << bit);
+    match heaviest {
+        2 => DOUBLE_JUNCTIONS[shape],
+        _ => SINGLE_JUNCTIONS[shape],
+    }
+}
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (box_rows, box_cols) = self.box_size();
+        let cards = (0..self.cells.len())
+            .map(|index| {
+                let card = self.cells[index].get_print_card(box_rows, box_cols);
+                // The diagonals of a Sudoku-X board are shaded, so it's clear which cells they hold
+                card.split('\n')
+                    .map(|line| match self.on_diagonal(index) {
+                        true => line.on_bright_black().to_string(),
+                        false => line.to_string(),
+                    })
+                    .collect::<Vec<String>>()
+            })
+            .collect::<Vec<Vec<String>>>();
+        write!(f, "{:9}", self.draw(&cards).join("\n"))
     }
 }
 // endregion Print
@@ -0,0 +1,90 @@
+//! Sodoku solving, stepping, rating and generation.
+//!
+//! The typical flow is to parse a board with [`Grid::from_string`], pick the techniques that are
+//! allowed with [`solvers::get_solvers`] (or use every technique in [`solvers::SOLVERS`]), and then
+//! either solve it outright with [`solvers::solve_subset`], or advance it one deduction at a time
+//! with [`solvers::step`], which returns a [`Deduction`] describing what was found. Anything that
+//! can fail on a bad board returns a [`SudokuError`] instead of panicking.
+//!
+//! ```
+//! use sodoku_solver::{solvers, Grid};
+//!
+//! let board = "\
+//! 200006754
+//! 007904180
+//! 384007020
+//! 500082070
+//! 038700400
+//! 009600005
+//! 005370090
+//! 870060001
+//! 013058000";
+//! let mut grid = Grid::from_string(board, None, true).unwrap();
+//! solvers::solve(&mut grid).unwrap();
+//! assert!(grid.is_done());
+//! ```
+pub mod cell;
+pub mod constraint;
+pub mod deduction;
+pub mod error;
+pub mod generator;
+pub mod grid;
+pub mod solvers;
+
+pub use crate::cell::Cell;
+pub use crate::constraint::{Cage, Constraint};
+pub use crate::deduction::Deduction;
+pub use crate::error::SudokuError;
+pub use crate::generator::{create_board, rate_board, SolveDifficulty};
+pub use crate::grid::Grid;
+pub use crate::solvers::Solver;
+use std::fmt;
+use std::fmt::Formatter;
+
+/// The largest board side length supported, so every value fits a letter from A-P after 1-9
+pub const MAX_SIZE: usize = 25;
+
+/// The kinds of group a cell can belong to, in the order used by [`Grid::unsolved_groups`]. Every
+/// cell is in a row, a column and a region, extra houses are added by variants with
+/// [`Grid::add_house`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GroupType {
+    Rows,
+    Columns,
+    Regions,
+    Extra,
+}
+/// A row/column coordinate on the board, both zero based, ordered by row and then column
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+impl Position {
+    pub const fn new(row: usize, col: usize) -> Position {
+        if row >= MAX_SIZE || col >= MAX_SIZE {
+            panic!("Position out of bounds");
+        }
+        Position { row, col }
+    }
+}
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "r{}c{}", self.row + 1, self.col + 1)
+    }
+}
+/// The character used for a value when reading or printing a board, 1-9 and then A-P for 10-25
+pub fn value_to_char(value: u8) -> char {
+    match value {
+        1..=9 => char::from(b'0' + value),
+        _ => char::from(b'A' + value - 10),
+    }
+}
+/// The value a character stands for, see [`value_to_char`], letters can be either case
+pub fn char_to_value(character: char) -> Option<u8> {
+    match character.to_ascii_uppercase() {
+        digit @ '1'..='9' => Some(digit as u8 - b'0'),
+        letter @ 'A'..='P' => Some(letter as u8 - b'A' + 10),
+        _ => None,
+    }
+}
@@ -1,159 +1,172 @@
-mod cell;
-mod generator;
-mod grid;
+mod cli;
+mod play;
 mod sodoku_output;
-mod solvers;
+mod terminal;
 mod tests;
 
-use crate::grid::Grid;
-use crate::solvers::{get_solvers, print_and_flush_grid_changes, Solver, SOLVERS};
+use crate::cli::{parse_yes_no, query_args_or_user, CommandArgs};
+use crate::terminal::print_and_flush_grid_changes;
 use crate::tests::Test;
 use clearscreen::clear;
-use std::collections::HashMap;
-use std::env::args;
-use std::fmt::Debug;
+use sodoku_solver::solvers::{get_solvers, BRUTE_FORCE, SOLVERS, UNIQUENESS_SOLVERS};
+use sodoku_solver::{generator, solvers, Grid, Solver, SudokuError};
 use std::io;
 use std::io::stdin;
 use std::ops::Add;
 
-struct CommandArgs {
-    arg_map: HashMap<String, String>,
-}
-impl CommandArgs {
-    fn new() -> CommandArgs {
-        let mut arg_map: HashMap<String, String> = Default::default();
-        for arg in args().skip(1) {
-            let sides = arg.split_once("=");
-            if sides.is_none() {
-                arg_map.insert(arg.clone(), "".to_string());
-            } else {
-                let (key, value) = sides.unwrap();
-                arg_map.insert(key.to_string(), value.to_string());
-            }
+// The longest a timing test can take to solve its board
+const TIMING_LIMIT: std::time::Duration = std::time::Duration::from_secs(2);
+
+fn run_test(test: Test) {
+    let mut grid = match Grid::from_string(test.board, Some(test.solution()), true) {
+        Ok(grid) => grid,
+        Err(error) => {
+            println!("Failed: {}", error);
+            return;
         }
-        CommandArgs { arg_map }
-    }
-    fn get_arg(&self, key: &str) -> Option<&String> {
-        self.arg_map.get(key)
+    };
+    if let Err(error) = solvers::solve(&mut grid) {
+        println!("Failed: {}", error);
+        grid.print_board();
+        return;
     }
-    fn has_arg(&self, key: &str) -> bool {
-        self.arg_map.contains_key(key)
+    let percent = grid.get_percent();
+    if percent < 1f32 {
+        println!("Failed: {}%", percent * 100f32);
+        grid.print_board();
+        grid.print_possibilities();
+    } else {
+        println!("Passed");
     }
 }
-#[allow(dead_code)]
-enum GroupType {
-    Rows,
-    Columns,
-    Regions,
-}
-#[derive(Copy, Clone, Debug)]
-struct Position {
-    row: usize,
-    col: usize,
-}
-impl Position {
-    const fn new(row: usize, col: usize) -> Position {
-        if row > 8 || col > 8 {
-            panic!("Position out of bounds");
+// Solves the board with only the basic rules, and then the rule the test is for once they get
+// stuck, so the test fails unless that rule is needed and works
+fn run_rule_test(rule: &str, test: Test) {
+    let mut grid = match Grid::from_string(test.board, Some(test.solution()), true) {
+        Ok(grid) => grid,
+        Err(error) => {
+            println!("Failed: {}", error);
+            return;
         }
-        Position { row, col }
+    };
+    if solvers::needs_uniqueness(&get_solvers(rule)) {
+        grid.verify_uniqueness();
     }
-    const fn region(&self) -> (usize, usize) {
-        (
-            self.row / 3 * 3 + self.col / 3,
-            (self.row % 3) * 3 + self.col % 3,
-        )
+    match count_rule_uses(&mut grid, rule) {
+        Err(error) => {
+            println!("Failed: {}", error);
+            grid.print_board();
+        }
+        Ok(0) => println!("Failed: {} was never used", rule),
+        Ok(_) if !grid.is_done() => {
+            println!("Failed: {}%", grid.get_percent() * 100f32);
+            grid.print_board();
+            grid.print_possibilities();
+        }
+        Ok(_) => println!("Passed"),
     }
-    const fn from_index(index: usize) -> Position {
-        Position {
-            col: index % 9,
-            row: index / 9,
+}
+// Solves the board without checking it only has one solution first, which has to keep a rule
+// that relies on it from doing anything
+fn run_unverified_test(rule: &str, test: Test) {
+    let mut grid = match Grid::from_string(test.board, Some(test.solution()), true) {
+        Ok(grid) => grid,
+        Err(error) => {
+            println!("Failed: {}", error);
+            return;
         }
+    };
+    match count_rule_uses(&mut grid, rule) {
+        Err(error) => println!("Failed: {}", error),
+        Ok(0) => println!("Passed"),
+        Ok(_) => println!("Failed: {} was used on an unchecked board", rule),
     }
-    const fn get_index(&self) -> usize {
-        self.row * 9 + self.col
+}
+// Solves a board with more than one solution, where none of the rules that rely on there only
+// being one should ever be used
+fn run_multiple_solutions_test(board: &str) {
+    let mut grid = match Grid::from_string(board, None, true) {
+        Ok(grid) => grid,
+        Err(error) => {
+            println!("Failed: {}", error);
+            return;
+        }
+    };
+    if grid.verify_uniqueness() {
+        println!("Failed: the board only has one solution");
+        return;
     }
+    run_without_uniqueness(&mut grid);
 }
-fn parse_yes_no(input: &str) -> Option<bool> {
-    let mut start = input.chars().nth(0).unwrap();
-    start = start.to_ascii_lowercase();
-    if start == 'n' || start == 'f' {
-        return Some(false);
-    } else if start == 'y' || start == 't' {
-        return Some(true);
+// Solves a variant board with one solution, where swapping values around a rectangle can break one
+// of its extra houses or constraints, so the rules that rely on that still can't be used
+fn run_variant_uniqueness_test(test: Test) {
+    let mut grid = match Grid::from_string(test.board, Some(test.solution()), true) {
+        Ok(grid) => grid,
+        Err(error) => {
+            println!("Failed: {}", error);
+            return;
+        }
+    };
+    if !grid.verify_uniqueness() {
+        println!("Failed: the board has more than one solution");
+        return;
     }
-    None
+    run_without_uniqueness(&mut grid);
 }
-
-const fn generate_groups() -> [[[usize; 9]; 9]; 3] {
-    let mut rows: [[usize; 9]; 9] = [[0; 9]; 9];
-    let mut cols: [[usize; 9]; 9] = [[0; 9]; 9];
-    let mut regions: [[usize; 9]; 9] = [[0; 9]; 9];
-    let mut i = 0;
+// Applies the basic rules and the uniqueness rules, failing if any of the uniqueness rules are used
+fn run_without_uniqueness(grid: &mut Grid) {
+    let mut solver_set = get_solvers(tests::all_tests::BASIC_RULES);
+    solver_set.extend(UNIQUENESS_SOLVERS);
     loop {
-        if i == 9 {
-            break;
-        }
-        let mut j = 0;
-        loop {
-            if j == 9 {
-                break;
+        match solvers::step(grid, &solver_set) {
+            Err(error) => return println!("Failed: {}", error),
+            Ok(None) => return println!("Passed"),
+            Ok(Some(deduction)) if solvers::needs_uniqueness(&[&deduction.technique]) => {
+                return println!("Failed: {} was used", deduction.technique.abbreviation)
             }
-            rows[i][j] = i * 9 + j;
-            cols[i][j] = j * 9 + i;
-            let (reg_x, reg_y) = Position { row: i, col: j }.region();
-            regions[reg_x][reg_y] = i * 9 + j;
-            j += 1;
+            Ok(Some(_)) => {}
         }
-        i += 1;
     }
-    [rows, cols, regions]
 }
-static COLLECTIONS: [[[usize; 9]; 9]; 3] = generate_groups();
-static ROWS: &[[usize; 9]; 9] = &COLLECTIONS[0];
-static COLS: &[[usize; 9]; 9] = &COLLECTIONS[1];
-static REGS: &[[usize; 9]; 9] = &COLLECTIONS[2];
-
-fn run_test(test: Test, arguments: &CommandArgs) {
-    let mut grid = Grid::from_string(test.board, Some(*test.answer), true).unwrap();
-    solvers::solve(&mut grid, &arguments);
-    let percent = grid.get_percent();
-    if percent < 1f32 {
-        println!("Failed: {}%", percent * 100f32);
-        grid.print_board();
-        grid.print_possibilities();
+// Solves the board with the basic rules and then the given rules, failing if it takes longer than
+// TIMING_LIMIT
+fn run_timing_test(rules: &str, test: Test) {
+    let mut grid = match Grid::from_string(test.board, Some(test.solution()), true) {
+        Ok(grid) => grid,
+        Err(error) => {
+            println!("Failed: {}", error);
+            return;
+        }
+    };
+    let mut solver_set = get_solvers(tests::all_tests::BASIC_RULES);
+    solver_set.extend(get_solvers(rules));
+    let start_time = std::time::Instant::now();
+    if let Err(error) = solvers::solve_subset(&mut grid, &solver_set) {
+        println!("Failed: {}", error);
+        return;
+    }
+    let elapsed = start_time.elapsed();
+    if !grid.is_done() {
+        println!("Failed: {}%", grid.get_percent() * 100f32);
+    } else if elapsed > TIMING_LIMIT {
+        println!("Failed: took {:?}", elapsed);
     } else {
         println!("Passed");
     }
 }
-fn query_args_or_user<P, T>(
-    prompt: &str,
-    failure_message: &str,
-    arg_flag: &str,
-    arguments: &CommandArgs,
-    mut validity_test: P,
-) -> (String, T)
-where
-    P: FnMut(&str) -> Option<T>,
-{
-    let arg = arguments.get_arg(arg_flag);
-    if arg.is_some() {
-        let validity = validity_test(arg.unwrap());
-        if validity.is_some() {
-            return (arg.unwrap().to_string(), validity.unwrap());
-        }
-        println!("{}", failure_message);
-    }
-    loop {
-        println!("{}", prompt);
-        let mut result = String::new();
-        stdin().read_line(&mut result).expect("Failed to read line");
-        let validity = validity_test(&*result);
-        if validity.is_some() {
-            return (result, validity.unwrap());
+// Applies the basic rules and then the given rule one step at a time, returning how many of the
+// steps were made by the given rule
+fn count_rule_uses(grid: &mut Grid, rule: &str) -> Result<usize, SudokuError> {
+    let mut solver_set = get_solvers(tests::all_tests::BASIC_RULES);
+    solver_set.extend(get_solvers(rule));
+    let mut uses = 0;
+    while let Some(deduction) = solvers::step(grid, &solver_set)? {
+        if deduction.technique.abbreviation == rule {
+            uses += 1;
         }
-        println!("{}", failure_message);
     }
+    Ok(uses)
 }
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -161,12 +174,13 @@ enum RunType {
     Solve,
     Generate,
     Test,
+    Play,
     Time,
     Display,
     NYTimes,
 }
 impl RunType {
-    const ITERATOR: [Self; 3] = [Self::Solve, Self::Generate, Self::Test];
+    const ITERATOR: [Self; 4] = [Self::Solve, Self::Generate, Self::Test, Self::Play];
     fn parse(input: &str) -> Option<RunType> {
         let input_lower = input.to_lowercase();
         let mut starts: Vec<RunType> = Vec::new();
@@ -185,7 +199,7 @@ impl RunType {
         }
         if starts.len() == 1 {
             return Some(starts[0].clone());
-        } else if starts.len() == 0 && overlaps.len() == 1 {
+        } else if starts.is_empty() && overlaps.len() == 1 {
             return Some(overlaps[0].clone());
         }
         None
@@ -197,60 +211,104 @@ fn print_help() {
     // Also need support for flagged args instead of just assigned args, that support concatenation
     println!("-h: Prints this help section");
     println!(
-        "-m: Selects what mode to run in, valid inputs are any abbreviated version of Solve, Generate, or Test"
+        "-m: Selects what mode to run in, valid inputs are any abbreviated version of Solve, Generate, Test, or Play"
+    );
+    println!(
+        "-b: The board to use in Solve or Play mode, spaces or 0s can be used for unknown cells, use \\n for line breaks, surround in quotes"
     );
     println!(
-        "-b: The board to use in Solve mode, spaces or 0s can be used for unknown cells, use \\n for line breaks, surround in quotes"
+        "-s: In generate mode, the size of the board, any size up to 25 that splits into regions, e.g. 4, 6, 9, 12, 16 "
+    );
+    println!(
+        "-j: In generate mode, make a jigsaw board, where the regions are random shapes, yes/no "
+    );
+    println!(
+        "-x: In generate mode, make a Sudoku-X board, where both diagonals are houses too, yes/no "
+    );
+    println!(
+        "-i: In solve mode, give hints for each step a piece at a time instead of solving, yes/no "
     );
     println!("-t: Choose whether or not to show how to solve a board in solve mode, yes/no ");
     println!(
         "-a: If using -t, this determines whether to auto-advance, or wait for using input, yes/no "
     );
+    println!(
+        "-f: In solve mode, finish the board by brute force if no logical step can be found, yes/no "
+    );
+    println!(
+        "-u: In solve mode, also use techniques that rely on the board having only one solution, yes/no "
+    );
 }
 fn input_sodoku_board(arguments: &CommandArgs) -> Grid {
     clear().expect("Failed to clear screen");
-    let arg_board = arguments.get_arg("-b");
-    if arg_board.is_some() {
-        let arg_board = arg_board.unwrap().replace("\\n", "\n");
-        let grid = Grid::from_string(arg_board.as_str(), None, false);
-        if grid.is_some() {
-            return grid.unwrap();
+    if let Some(arg_board) = arguments.get_arg("-b") {
+        let arg_board = arg_board.replace("\\n", "\n");
+        match Grid::from_string(arg_board.as_str(), None, false) {
+            Ok(grid) => return grid,
+            Err(error) => println!("Failed to parse passed in board: {}", error),
         }
-        println!("Failed to parse passed in board");
     }
     loop {
         println!("Please enter your board");
-        println!("Use 1-9 for known digits, 0 or ' ' can be used for unknown cells");
+        println!("Use 1-9 for known digits, then A-P on boards larger than 9x9, 0 or ' ' can be used for unknown cells");
         println!("You can use '|' to help space out digits, though they are not necessary");
-        let mut board;
-        loop {
-            board = "".to_string();
-            for i in 0..9 {
-                let mut new_line: String = String::new();
-                stdin()
-                    .read_line(&mut new_line)
-                    .expect("Failed to read line");
+        println!("The length of the first row sets the size of the board");
+        println!("Start with a line of just X for Sudoku-X, where both diagonals hold each digit once too");
+        let mut board = "".to_string();
+        let mut size = None;
+        let mut i = 0;
+        while size.is_none_or(|(size, _)| i < size) {
+            let mut new_line: String = String::new();
+            stdin()
+                .read_line(&mut new_line)
+                .expect("Failed to read line");
 
-                new_line.retain(|c| c != '|');
-                board += new_line.as_str();
-                if i == 2 || i == 5 {
-                    println!("-----------")
-                }
+            new_line.retain(|c| c != '|');
+            board += new_line.as_str();
+            if i == 0 && new_line.trim().eq_ignore_ascii_case("x") {
+                continue;
+            }
+            i += 1;
+            let (size, box_rows) = *size.get_or_insert_with(|| {
+                let size = new_line.trim_end_matches(['\r', '\n']).chars().count();
+                (
+                    size,
+                    Grid::box_shape(size).map_or(size, |(box_rows, _)| box_rows),
+                )
+            });
+            if i % box_rows == 0 && i != size {
+                println!("{}", "-".repeat(size + size / box_rows - 1))
             }
-            break;
         }
-        let grid = Grid::from_string(board.as_str(), None, false);
-        if grid.is_some() {
-            return grid.unwrap();
+        println!("For a jigsaw board, enter a line per row naming each cell's region, e.g. with the letters A-I");
+        println!(
+            "For killer sudoku, enter a line per cage with its sum and cells, e.g. 12: r1c1 r1c2"
+        );
+        println!("Press enter on an empty line when done");
+        // An empty line splits the board from its regions and cages
+        board += "\n";
+        loop {
+            let mut new_line: String = String::new();
+            stdin()
+                .read_line(&mut new_line)
+                .expect("Failed to read line");
+            if new_line.trim().is_empty() {
+                break;
+            }
+            new_line.retain(|c| c != '|');
+            board += new_line.as_str();
+        }
+        match Grid::from_string(board.as_str(), None, false) {
+            Ok(grid) => return grid,
+            Err(error) => println!("Failed to parse board: {}", error),
         }
-        println!("Failed to parse board");
         std::thread::sleep(std::time::Duration::from_millis(1000));
         clear().expect("Failed to clear screen");
     }
 }
 fn select_mode(arguments: &CommandArgs) -> RunType {
     let (_, run_type) = query_args_or_user(
-        "Select Sodoku Mode: Solve, Generate, Test",
+        "Select Sodoku Mode: Solve, Generate, Test, Play",
         "Invalid Mode",
         "-m",
         arguments,
@@ -260,24 +318,92 @@ fn select_mode(arguments: &CommandArgs) -> RunType {
 }
 fn mode_solve(arguments: &CommandArgs) {
     let mut grid = input_sodoku_board(arguments);
-    let (_, is_async) = query_args_or_user(
-        "Would you like to see it step by step? Yes/No",
+    let solutions = grid.find_solutions(2);
+    if solutions.is_empty() {
+        println!("{}", grid);
+        println!("This board has no solution");
+        return;
+    } else if solutions.len() > 1 {
+        println!("{}", grid);
+        println!("This board has multiple solutions, here are two:");
+        terminal::print_solutions(&solutions, grid.box_size().1);
+        return;
+    }
+    grid.verify_uniqueness();
+    let (_, wants_hints) = query_args_or_user(
+        "Would you like hints instead of the solution? Yes/No",
+        "Invalid input",
+        "-i",
+        arguments,
+        parse_yes_no,
+    );
+    let is_async = !wants_hints
+        && query_args_or_user(
+            "Would you like to see it step by step? Yes/No",
+            "Invalid input",
+            "-t",
+            arguments,
+            parse_yes_no,
+        )
+        .1;
+    let mut solver_set = SOLVERS.to_vec();
+    let (_, use_uniqueness) = query_args_or_user(
+        "Would you like to use techniques that rely on the board having one solution? Yes/No",
+        "Invalid input",
+        "-u",
+        arguments,
+        parse_yes_no,
+    );
+    if use_uniqueness {
+        solver_set.extend(UNIQUENESS_SOLVERS);
+    }
+    let (_, use_fallback) = query_args_or_user(
+        "Would you like to finish the board by brute force if it gets stuck? Yes/No",
         "Invalid input",
-        "-t",
+        "-f",
         arguments,
-        |x| parse_yes_no(x),
+        parse_yes_no,
     );
-    if is_async {
-        solvers::solve_async(&mut grid, arguments);
+    if use_fallback {
+        solver_set.push(&BRUTE_FORCE);
+    }
+    let result = if wants_hints {
+        terminal::give_hints(&mut grid, &solver_set)
+    } else if is_async {
+        terminal::solve_async(&mut grid, &solver_set, arguments)
     } else {
-        solvers::solve(&mut grid, arguments);
+        let result = solvers::solve_subset(&mut grid, &solver_set);
         clear().expect("Failed to clear screen");
-    }
+        result
+    };
     print_and_flush_grid_changes(&mut io::stdout(), &mut grid, None);
+    match result {
+        Err(error) => println!("\n{}", error),
+        Ok(()) if !grid.is_done() => println!(
+            "\nGot stuck after solving {:.0}% of the board, run with -f=yes to finish it by brute force",
+            grid.get_percent() * 100f32
+        ),
+        Ok(()) => {}
+    }
+}
+fn mode_play(arguments: &CommandArgs) {
+    let grid = input_sodoku_board(arguments);
+    if grid.size() > 9 {
+        println!("{}", grid);
+        println!("Only boards up to 9x9 can be played, values are typed as digits");
+        return;
+    }
+    let mut solutions = grid.find_solutions(2);
+    if solutions.len() != 1 {
+        println!("{}", grid);
+        println!("Only boards with exactly one solution can be played");
+        return;
+    }
+    play::play(grid, solutions.remove(0));
 }
 fn construct_codes() -> String {
     let mut string: String = Default::default();
-    for solver in SOLVERS {
+    for solver in SOLVERS.into_iter().chain(UNIQUENESS_SOLVERS) {
         if solver.abbreviation == "N1" {
             continue;
         }
@@ -308,10 +434,46 @@ fn mode_generate(arguments: &CommandArgs) {
             try_get_solvers(x.to_string())
         });
 
+    let size = arguments
+        .get_arg("-s")
+        .and_then(|x| x.trim().parse::<usize>().ok());
+    let (box_rows, box_cols) = match size.map(Grid::box_shape) {
+        None => (3, 3),
+        Some(Some(box_shape)) => box_shape,
+        Some(None) => {
+            println!("That size can't be split into regions, using 9x9");
+            (3, 3)
+        }
+    };
+
+    let jigsaw = arguments
+        .get_arg("-j")
+        .and_then(|x| parse_yes_no(x))
+        .unwrap_or(false);
+    let mut layout = match jigsaw {
+        true => generator::create_jigsaw_layout(box_rows, box_cols),
+        false => Grid::with_box_size(box_rows, box_cols),
+    };
+    if arguments
+        .get_arg("-x")
+        .and_then(|x| parse_yes_no(x))
+        .unwrap_or(false)
+    {
+        layout
+            .add_diagonals()
+            .expect("An empty board can always take diagonals");
+    }
+
     let start_time = std::time::Instant::now();
-    let grid = generator::create_board(solvers);
+    let grid = generator::create_variant_board(&solvers, &layout);
     println!("Create Time: {:?}", start_time.elapsed());
 
+    if let Ok(Some(rating)) = generator::rate_board(&grid, &solvers) {
+        println!("Rules Used:");
+        for (count, solver) in rating.solver_counts.iter().zip(solvers.iter()) {
+            println!("\t{} {}", count, solver.name);
+        }
+    }
     println!("{}", grid);
 }
 fn main() {
@@ -332,35 +494,54 @@ fn main() {
             mode_generate(&arguments)
             //solvers::solve_async(&mut grid);
         }
+        RunType::Play => {
+            mode_play(&arguments);
+        }
         RunType::Test => {
             println!("Completed Tests:");
             for i in tests::all_tests::ALL_SOLVED_TESTS {
-                run_test(i, &arguments);
+                run_test(i);
+            }
+            println!("Rule Tests:");
+            for (rule, test) in tests::all_tests::RULE_TESTS {
+                run_rule_test(rule, test);
+            }
+            println!("Uniqueness Tests:");
+            for (rule, test) in tests::all_tests::RULE_TESTS {
+                if solvers::needs_uniqueness(&get_solvers(rule)) {
+                    run_unverified_test(rule, test);
+                }
+            }
+            run_multiple_solutions_test(tests::rule_tests::MULTIPLE_SOLUTIONS);
+            run_variant_uniqueness_test(tests::rule_tests::SUDOKU_X_RECTANGLES);
+            println!("Timing Tests:");
+            for (rules, test) in tests::all_tests::TIMING_TESTS {
+                run_timing_test(rules, test);
             }
             println!("Uncompleted Tests:");
             for i in tests::all_tests::ALL_UNSOLVED_TESTS {
-                run_test(i, &arguments);
+                run_test(i);
             }
         }
 
         RunType::Display => {
             let mut grid = Grid::from_string(test.board, None, true).unwrap();
-            solvers::solve_async(&mut grid, &arguments);
+            terminal::solve_async(&mut grid, &SOLVERS, &arguments).unwrap();
         }
         RunType::Time => {
             let mut grid: Grid;
             const ITERATIONS: usize = 10000;
             let start_time = std::time::Instant::now();
             for _ in 0..ITERATIONS {
-                grid = Grid::from_string(test.board, Some(*test.answer), true).unwrap();
-                solvers::solve(&mut grid, &arguments);
+                grid = Grid::from_string(test.board, Some(test.solution()), true).unwrap();
+                solvers::solve(&mut grid).unwrap();
             }
             println!("Solve Time: {:?}", start_time.elapsed() / ITERATIONS as u32);
         }
         RunType::NYTimes => {
             std::thread::sleep(std::time::Duration::from_millis(2000));
             let mut grid = Grid::from_string(test.board, None, true).unwrap();
-            solvers::solve(&mut grid, &arguments);
+            solvers::solve(&mut grid).unwrap();
             let start_time = std::time::Instant::now();
             sodoku_output::send_input(grid);
             println!("Solve Time: {:?}", start_time.elapsed());
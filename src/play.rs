@@ -0,0 +1,248 @@
+use crate::terminal::describe_location;
+use colored::Colorize;
+use crossterm::event::{read, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::{cursor, style, terminal, ExecutableCommand, QueueableCommand};
+use sodoku_solver::cell::print_card;
+use sodoku_solver::grid::Solution;
+use sodoku_solver::solvers::{step, SOLVERS};
+use sodoku_solver::{Deduction, Grid};
+use std::io;
+use std::io::{Stdout, Write};
+
+const KEYS: &str = "Arrows: move  1-9: enter  0/Backspace: clear  p: pencil marks  a: auto candidates\r\nh: hint  c: check  q: quit";
+
+// A board being solved by hand, with the player's values and pencil marks kept apart from the
+// givens, so they can be wrong or conflict without breaking anything
+struct Play {
+    givens: Grid,
+    solution: Solution,
+    values: Vec<u8>,
+    pencil_marks: Vec<u32>,
+    cursor: usize,
+    pencil_mode: bool,
+    auto_candidates: bool,
+    show_mistakes: bool,
+    // The next step, and how much of it has been revealed
+    hint: Option<(Deduction, usize)>,
+    message: String,
+}
+impl Play {
+    fn is_given(&self, index: usize) -> bool {
+        self.givens.cells[index].is_given()
+    }
+    // Every value that isn't already in one of the cell's groups
+    fn auto_candidates(&self, index: usize) -> u32 {
+        self.givens
+            .get_cell_groups(self.givens.position(index))
+            .iter()
+            .flat_map(|group| group.iter())
+            .fold(self.givens.all_candidates(), |acc, &other| {
+                match self.values[other] {
+                    0 => acc,
+                    value => acc & !(1 << (value - 1)),
+                }
+            })
+    }
+    fn is_conflict(&self, index: usize) -> bool {
+        let value = self.values[index];
+        value != 0
+            && self
+                .givens
+                .get_cell_groups(self.givens.position(index))
+                .iter()
+                .flat_map(|group| group.iter())
+                .any(|&other| other != index && self.values[other] == value)
+    }
+    fn is_mistake(&self, index: usize) -> bool {
+        let position = self.givens.position(index);
+        let value = self.values[index];
+        value != 0 && value != self.solution[position.row][position.col]
+    }
+    fn is_solved(&self) -> bool {
+        (0..self.values.len()).all(|index| {
+            let position = self.givens.position(index);
+            self.values[index] == self.solution[position.row][position.col]
+        })
+    }
+    fn enter(&mut self, value: u8) {
+        if self.is_given(self.cursor) {
+            self.message = "That cell is a given".to_string();
+            return;
+        }
+        if value as usize > self.givens.size() {
+            self.message = format!("Values only go up to {}", self.givens.size());
+            return;
+        }
+        if self.pencil_mode && value != 0 {
+            self.pencil_marks[self.cursor] ^= 1 << (value - 1);
+            return;
+        }
+        self.values[self.cursor] = value;
+        self.hint = None;
+        self.show_mistakes = false;
+        if self.is_solved() {
+            self.message = "Solved!".to_string();
+        }
+    }
+    fn check(&mut self) {
+        let mistakes = (0..self.values.len())
+            .filter(|&index| self.is_mistake(index))
+            .count();
+        self.show_mistakes = true;
+        self.message = match mistakes {
+            0 => "Everything so far is correct".to_string(),
+            1 => "1 value is wrong".to_string(),
+            count => format!("{} values are wrong", count),
+        };
+    }
+    // Reveals a bit more of the next step each time, the technique, then where to look, then
+    // everything it changes
+    fn hint(&mut self) {
+        if let Some((_, shown)) = &mut self.hint {
+            *shown = (*shown + 1).min(3);
+            return;
+        }
+        if (0..self.values.len()).any(|index| self.is_mistake(index)) {
+            self.message = "Some values are wrong, press c to see them".to_string();
+            return;
+        }
+        let board = self
+            .values
+            .chunks(self.givens.size())
+            .map(|row| {
+                row.iter()
+                    .map(|value| value.to_string())
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        let next =
+            Grid::from_string(&board, None, false).and_then(|mut grid| step(&mut grid, &SOLVERS));
+        match next {
+            Ok(Some(deduction)) => self.hint = Some((deduction, 1)),
+            Ok(None) => self.message = "None of the techniques can find a next step".to_string(),
+            Err(error) => self.message = error.to_string(),
+        }
+    }
+    fn hint_text(&self) -> Option<String> {
+        let (deduction, shown) = self.hint.as_ref()?;
+        let hints = [
+            format!("Next technique: {}", deduction.technique.name),
+            format!("Look at {}", describe_location(&self.givens, deduction)),
+            deduction.to_string(),
+        ];
+        Some(hints[..*shown].join("\n").replace('\n', "\r\n"))
+    }
+    fn card(&self, index: usize) -> Vec<String> {
+        let value = self.values[index];
+        let candidates = match (value, self.auto_candidates) {
+            (0, true) => self.auto_candidates(index),
+            (0, false) => self.pencil_marks[index],
+            _ => 0,
+        };
+        let (box_rows, box_cols) = self.givens.box_size();
+        print_card(value, candidates, box_rows, box_cols)
+            .split('\n')
+            .map(|line| {
+                let line = if self.is_given(index) {
+                    line.green()
+                } else if self.is_conflict(index) {
+                    line.red()
+                } else if self.show_mistakes && self.is_mistake(index) {
+                    line.yellow()
+                } else if value == 0 {
+                    line.dimmed()
+                } else {
+                    line.normal()
+                };
+                if index == self.cursor {
+                    line.reversed().to_string()
+                } else {
+                    line.to_string()
+                }
+            })
+            .collect()
+    }
+    fn draw(&self, stdout: &mut Stdout) {
+        let cards = (0..self.values.len())
+            .map(|index| self.card(index))
+            .collect::<Vec<Vec<String>>>();
+        let mut lines = self.givens.draw(&cards);
+        let modes = format!(
+            "{}: {}  Pencil marks: {}  Auto candidates: {}",
+            self.givens.position(self.cursor),
+            if self.is_given(self.cursor) {
+                "given"
+            } else {
+                "open"
+            },
+            if self.pencil_mode { "on" } else { "off" },
+            if self.auto_candidates { "on" } else { "off" },
+        );
+        lines.push(modes);
+        lines.push(KEYS.to_string());
+        lines.push(self.message.clone());
+        if let Some(hint) = self.hint_text() {
+            lines.push(hint);
+        }
+        stdout.queue(cursor::MoveTo(0, 0)).unwrap();
+        stdout
+            .queue(terminal::Clear(terminal::ClearType::All))
+            .unwrap();
+        stdout.queue(style::Print(lines.join("\r\n"))).unwrap();
+        stdout.flush().unwrap();
+    }
+}
+/// Plays a board of up to 9x9 by hand, values are typed as digits, so larger boards can't be played
+pub fn play(givens: Grid, solution: Solution) {
+    let values = givens
+        .cells
+        .iter()
+        .map(|cell| cell.value())
+        .collect::<Vec<u8>>();
+    let size = givens.size();
+    let mut game = Play {
+        givens,
+        solution,
+        pencil_marks: vec![0; values.len()],
+        values,
+        cursor: 0,
+        pencil_mode: false,
+        auto_candidates: false,
+        show_mistakes: false,
+        hint: None,
+        message: String::new(),
+    };
+    let mut stdout = io::stdout();
+    stdout.execute(terminal::EnterAlternateScreen).unwrap();
+    stdout.execute(cursor::Hide).unwrap();
+    terminal::enable_raw_mode().expect("Failed to enable raw mode");
+    loop {
+        game.draw(&mut stdout);
+        let Event::Key(KeyEvent { code, kind, .. }) = read().expect("Failed to read input") else {
+            continue;
+        };
+        if kind != KeyEventKind::Press {
+            continue;
+        }
+        game.message.clear();
+        let (row, col) = (game.cursor / size, game.cursor % size);
+        match code {
+            KeyCode::Up => game.cursor = (row + size - 1) % size * size + col,
+            KeyCode::Down => game.cursor = (row + 1) % size * size + col,
+            KeyCode::Left => game.cursor = row * size + (col + size - 1) % size,
+            KeyCode::Right => game.cursor = row * size + (col + 1) % size,
+            KeyCode::Char(digit @ '0'..='9') => game.enter(digit as u8 - b'0'),
+            KeyCode::Backspace | KeyCode::Delete => game.enter(0),
+            KeyCode::Char('p') => game.pencil_mode = !game.pencil_mode,
+            KeyCode::Char('a') => game.auto_candidates = !game.auto_candidates,
+            KeyCode::Char('h') => game.hint(),
+            KeyCode::Char('c') => game.check(),
+            KeyCode::Char('q') | KeyCode::Esc => break,
+            _ => {}
+        }
+    }
+    terminal::disable_raw_mode().expect("Failed to disable raw mode");
+    stdout.execute(cursor::Show).unwrap();
+    stdout.execute(terminal::LeaveAlternateScreen).unwrap();
+}
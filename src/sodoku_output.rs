@@ -1,5 +1,5 @@
-use crate::{Grid, ROWS};
 use rdev::{simulate, Button, EventType, Key};
+use sodoku_solver::{Grid, GroupType};
 
 const DELAY: std::time::Duration = std::time::Duration::from_millis(1);
 fn send(number: usize) {
@@ -26,19 +26,19 @@ fn click() {
 pub fn send_input(grid: Grid) {
     click();
     let mut forward = true;
-    for i in 0..9 {
+    for row in grid.unsolved_groups[GroupType::Rows as usize].iter() {
         if forward {
-            for j in 0..9 {
-                if !grid.cells[ROWS[i][j]].is_given {
-                    send(grid.cells[ROWS[i][j]].value as usize);
+            for &index in row.iter() {
+                if !grid.cells[index].is_given() {
+                    send(grid.cells[index].value() as usize);
                 }
                 simulate(&EventType::KeyPress(Key::RightArrow)).unwrap();
                 std::thread::sleep(DELAY);
             }
         } else {
-            for j in (0..9).rev() {
-                if !grid.cells[ROWS[i][j]].is_given {
-                    send(grid.cells[ROWS[i][j]].value as usize)
+            for &index in row.iter().rev() {
+                if !grid.cells[index].is_given() {
+                    send(grid.cells[index].value() as usize)
                 }
                 simulate(&EventType::KeyPress(Key::LeftArrow)).unwrap();
                 std::thread::sleep(DELAY);
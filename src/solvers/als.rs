@@ -0,0 +1,214 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::{value_to_char, MAX_SIZE};
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::ops::{BitAnd, BitOr, Not};
+
+// The largest sets to look for, bigger sets are rarely useful and the search grows too quickly on
+// large boards
+const MAX_ALS_CELLS: usize = 8;
+const CELL_SET_WORDS: usize = (MAX_SIZE * MAX_SIZE).div_ceil(64);
+
+/// A set of cells on the board, one bit per cell index
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub(crate) struct CellSet([u64; CELL_SET_WORDS]);
+impl CellSet {
+    pub(crate) fn all() -> CellSet {
+        CellSet([u64::MAX; CELL_SET_WORDS])
+    }
+    pub(crate) fn single(index: usize) -> CellSet {
+        let mut set = CellSet::default();
+        set.insert(index);
+        set
+    }
+    pub(crate) fn insert(&mut self, index: usize) {
+        self.0[index / 64] |= 1 << (index % 64);
+    }
+    pub(crate) fn contains(&self, index: usize) -> bool {
+        self.0[index / 64] & (1 << (index % 64)) != 0
+    }
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+    pub(crate) fn iter(self) -> impl Iterator<Item = usize> {
+        self.0.into_iter().enumerate().flat_map(|(i, mut word)| {
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(i * 64 + bit)
+            })
+        })
+    }
+}
+impl BitOr for CellSet {
+    type Output = CellSet;
+    fn bitor(self, rhs: CellSet) -> CellSet {
+        CellSet(std::array::from_fn(|i| self.0[i] | rhs.0[i]))
+    }
+}
+impl BitAnd for CellSet {
+    type Output = CellSet;
+    fn bitand(self, rhs: CellSet) -> CellSet {
+        CellSet(std::array::from_fn(|i| self.0[i] & rhs.0[i]))
+    }
+}
+impl Not for CellSet {
+    type Output = CellSet;
+    fn not(self) -> CellSet {
+        CellSet(self.0.map(|word| !word))
+    }
+}
+
+/// An Almost Locked Set, N unsolved cells in one group that only have N + 1 values between them,
+/// so if any one of those values is taken away, the rest are locked into the set
+pub(crate) struct Als {
+    pub(crate) cells: CellSet,
+    pub(crate) candidates: u32,
+    /// The cells in the set that could be each value, indexed by value - 1
+    pub(crate) value_cells: Vec<CellSet>,
+}
+impl Als {
+    pub(crate) fn has_value(&self, value: u8) -> bool {
+        self.candidates & (1 << (value - 1)) != 0
+    }
+    /// The cells and values of the set, e.g. {r1c1, r1c2} (123)
+    pub(crate) fn describe(&self, grid: &Grid) -> String {
+        let values = (1..=grid.size() as u8)
+            .filter(|&value| self.has_value(value))
+            .map(value_to_char)
+            .join("");
+        format!("{{{}}} ({})", format_cells(grid, self.cells), values)
+    }
+}
+/// Finds every Almost Locked Set in the unsolved groups, each set of cells is only returned once
+pub(crate) fn find_als(grid: &Grid) -> Vec<Als> {
+    let mut search = AlsSearch {
+        grid,
+        // Half a group is plenty for a useful set, and keeps small boards from pairing up more
+        // sets than they need
+        max_cells: MAX_ALS_CELLS.min(grid.size() / 2),
+        seen: HashSet::new(),
+        found: Vec::new(),
+    };
+    for group in grid.unsolved_groups.iter().flatten() {
+        let unsolved = group
+            .iter()
+            .copied()
+            .filter(|&index| grid.cells[index].value == 0)
+            .collect::<Vec<usize>>();
+        search.extend(&unsolved, 0, &mut Vec::new(), 0);
+    }
+    search.found
+}
+// Builds up sets of cells from one group a cell at a time, dropping a set as soon as it has more
+// values than the largest set could, so the search doesn't try every combination on large boards
+struct AlsSearch<'a> {
+    grid: &'a Grid,
+    max_cells: usize,
+    seen: HashSet<CellSet>,
+    found: Vec<Als>,
+}
+impl AlsSearch<'_> {
+    fn extend(
+        &mut self,
+        unsolved: &[usize],
+        start: usize,
+        chosen: &mut Vec<usize>,
+        candidates: u32,
+    ) {
+        // A set of every unsolved cell in the group would be fully locked, not almost
+        let max_cells = self.max_cells.min(unsolved.len().saturating_sub(1));
+        for next in start..unsolved.len() {
+            let index = unsolved[next];
+            let candidates = candidates | self.grid.cells[index].candidates;
+            if candidates.count_ones() as usize > max_cells + 1 {
+                continue;
+            }
+            chosen.push(index);
+            if candidates.count_ones() as usize == chosen.len() + 1 {
+                self.add(chosen, candidates);
+            }
+            if chosen.len() < max_cells {
+                self.extend(unsolved, next + 1, chosen, candidates);
+            }
+            chosen.pop();
+        }
+    }
+    fn add(&mut self, chosen: &[usize], candidates: u32) {
+        let cells = chosen.iter().fold(CellSet::default(), |acc, &index| {
+            acc | CellSet::single(index)
+        });
+        if !self.seen.insert(cells) {
+            return;
+        }
+        let mut value_cells = vec![CellSet::default(); self.grid.size()];
+        for &index in chosen {
+            for value in self.grid.cells[index].get_possibilities() {
+                value_cells[value as usize - 1].insert(index);
+            }
+        }
+        self.found.push(Als {
+            cells,
+            candidates,
+            value_cells,
+        });
+    }
+}
+/// The cells each cell sees
+pub(crate) fn peer_masks(grid: &Grid) -> Vec<CellSet> {
+    (0..grid.cells.len())
+        .map(|a| {
+            (0..grid.cells.len())
+                .filter(|&b| grid.sees(a, b))
+                .fold(CellSet::default(), |acc, b| acc | CellSet::single(b))
+        })
+        .collect()
+}
+/// The cells that see every one of the given cells
+pub(crate) fn seen_by_all(peers: &[CellSet], cells: CellSet) -> CellSet {
+    if cells.is_empty() {
+        return CellSet::default();
+    }
+    cells
+        .iter()
+        .fold(CellSet::all(), |acc, index| acc & peers[index])
+}
+/// Values both sets have, where every cell of one that could be the value sees every cell of the
+/// other that could be, so only one of the two sets can hold it
+pub(crate) fn restricted_commons(a: &Als, b: &Als, peers: &[CellSet]) -> u32 {
+    let mut restricted = 0;
+    for bit in 0..a.value_cells.len() {
+        let (cells_a, cells_b) = (a.value_cells[bit], b.value_cells[bit]);
+        if cells_a.is_empty() || cells_b.is_empty() || !(cells_a & cells_b).is_empty() {
+            continue;
+        }
+        if (cells_b & !seen_by_all(peers, cells_a)).is_empty() {
+            restricted |= 1 << bit;
+        }
+    }
+    restricted
+}
+/// Removes the value from every unsolved cell in the set
+pub(crate) fn remove_from_cells(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    value: u8,
+    cells: CellSet,
+) -> Result<bool, SudokuError> {
+    let mut removed = false;
+    for index in cells.iter() {
+        removed |= deduction.remove_possibility(&mut grid.cells[index], value)?;
+    }
+    Ok(removed)
+}
+pub(crate) fn format_cells(grid: &Grid, cells: CellSet) -> String {
+    cells
+        .iter()
+        .map(|index| grid.position(index).to_string())
+        .join(", ")
+}
@@ -0,0 +1,86 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::als::{
+    find_als, peer_masks, remove_from_cells, restricted_commons, seen_by_all,
+};
+use crate::solvers::Solver;
+use itertools::Itertools;
+
+pub const ALS_XY_WING: Solver = Solver::new(
+    "ALS-XY-Wing",
+    "AW",
+    "An Almost Locked Set C shares a restricted value X with set A, and another restricted value Y with set B, so either A or B is locked, any value Z they share has to be in one of them, and Z was removed from anything that sees all of those places",
+    solve_als_xy_wing,
+    step_als_xy_wing,
+);
+pub fn step_als_xy_wing(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&ALS_XY_WING);
+    als_xy_wing(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_als_xy_wing(grid: &mut Grid) -> Result<bool, SudokuError> {
+    als_xy_wing(grid, &mut Deduction::new(&ALS_XY_WING), false)
+}
+fn als_xy_wing(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    let sets = find_als(grid);
+    let peers = peer_masks(grid);
+    for pivot in sets.iter() {
+        // Every set linked to the pivot, along with the values restricted between them
+        let wings = sets
+            .iter()
+            .filter(|wing| (wing.cells & pivot.cells).is_empty())
+            .map(|wing| (wing, restricted_commons(wing, pivot, &peers)))
+            .filter(|(_, restricted)| *restricted != 0)
+            .collect::<Vec<_>>();
+        for (&(a, restricted_a), &(b, restricted_b)) in wings.iter().tuple_combinations() {
+            if !(a.cells & b.cells).is_empty() {
+                continue;
+            }
+            for (x, y) in (0..grid.size()).cartesian_product(0..grid.size()) {
+                if x == y || restricted_a & (1 << x) == 0 || restricted_b & (1 << y) == 0 {
+                    continue;
+                }
+                let shared = a.candidates & b.candidates & !(1 << x) & !(1 << y);
+                let mut removed = Vec::new();
+                for z in (0..grid.size()).filter(|&bit| shared & (1 << bit) != 0) {
+                    let z_cells = a.value_cells[z] | b.value_cells[z];
+                    let targets = seen_by_all(&peers, z_cells) & !(a.cells | b.cells | pivot.cells);
+                    if remove_from_cells(grid, deduction, z as u8 + 1, targets)? {
+                        removed.push(z + 1);
+                    }
+                }
+                if removed.is_empty() {
+                    continue;
+                }
+                dirty = true;
+                let cells = (a.cells | b.cells | pivot.cells)
+                    .iter()
+                    .collect::<Vec<usize>>();
+                deduction.add_pattern(
+                    &grid.cells,
+                    &cells,
+                    a.candidates | b.candidates | pivot.candidates,
+                );
+                deduction.detail = Some(format!(
+                    "A = {}, B = {}, C = {}, restricted commons {} and {}, removed {}",
+                    a.describe(grid),
+                    b.describe(grid),
+                    pivot.describe(grid),
+                    x + 1,
+                    y + 1,
+                    removed.iter().join(", ")
+                ));
+                if single_step {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+    Ok(dirty)
+}
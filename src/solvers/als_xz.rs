@@ -0,0 +1,68 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::als::{
+    find_als, peer_masks, remove_from_cells, restricted_commons, seen_by_all,
+};
+use crate::solvers::Solver;
+use itertools::Itertools;
+
+pub const ALS_XZ: Solver = Solver::new(
+    "ALS-XZ",
+    "AX",
+    "Two Almost Locked Sets share a value X that can only be in one of them, so the other is locked, any other value Z they share has to be in one of them, and Z was removed from anything that sees all of those places",
+    solve_als_xz,
+    step_als_xz,
+);
+pub fn step_als_xz(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&ALS_XZ);
+    als_xz(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_als_xz(grid: &mut Grid) -> Result<bool, SudokuError> {
+    als_xz(grid, &mut Deduction::new(&ALS_XZ), false)
+}
+fn als_xz(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    let sets = find_als(grid);
+    let peers = peer_masks(grid);
+    for (a, b) in sets.iter().tuple_combinations() {
+        if !(a.cells & b.cells).is_empty() {
+            continue;
+        }
+        let restricted = restricted_commons(a, b, &peers);
+        for x in (0..grid.size()).filter(|&bit| restricted & (1 << bit) != 0) {
+            let mut removed = Vec::new();
+            for z in (0..grid.size())
+                .filter(|&bit| bit != x && (a.candidates & b.candidates) & (1 << bit) != 0)
+            {
+                let z_cells = a.value_cells[z] | b.value_cells[z];
+                let targets = seen_by_all(&peers, z_cells) & !(a.cells | b.cells);
+                if remove_from_cells(grid, deduction, z as u8 + 1, targets)? {
+                    removed.push(z + 1);
+                }
+            }
+            if removed.is_empty() {
+                continue;
+            }
+            dirty = true;
+            let cells = (a.cells | b.cells).iter().collect::<Vec<usize>>();
+            deduction.add_pattern(&grid.cells, &cells, a.candidates | b.candidates);
+            deduction.detail = Some(format!(
+                "A = {}, B = {}, restricted common {}, removed {}",
+                a.describe(grid),
+                b.describe(grid),
+                x + 1,
+                removed.iter().join(", ")
+            ));
+            if single_step {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(dirty)
+}
@@ -0,0 +1,205 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::{Grid, Layout, Solution};
+use crate::solvers::Solver;
+use std::sync::Arc;
+
+pub const BRUTE_FORCE: Solver = Solver::new(
+    "Brute Force",
+    "BF",
+    "No logical step was found, so this cell was filled in from a solution found by trying every possibility",
+    solve_brute_force,
+    step_brute_force,
+);
+pub fn step_brute_force(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let solution = find_solution(grid).ok_or(SudokuError::NoSolution)?;
+    let mut deduction = Deduction::new(&BRUTE_FORCE);
+    if let Some(index) = (0..grid.cells.len()).find(|&index| grid.cells[index].value == 0) {
+        let pos = grid.position(index);
+        deduction.set_cell(grid, pos, solution[pos.row][pos.col])?;
+    }
+    Ok(deduction.finish())
+}
+pub fn solve_brute_force(grid: &mut Grid) -> Result<bool, SudokuError> {
+    let solution = find_solution(grid).ok_or(SudokuError::NoSolution)?;
+    let mut dirty = false;
+    for index in 0..grid.cells.len() {
+        if grid.cells[index].value != 0 {
+            continue;
+        }
+        let pos = grid.position(index);
+        grid.set_cell(pos, solution[pos.row][pos.col])?;
+        dirty = true;
+    }
+    Ok(dirty)
+}
+/// Finds a solution to the board by trying every possibility, without using any logical steps.
+/// Returns None if the board can't be solved, if there are several solutions, the first one found
+/// is returned
+pub fn find_solution(grid: &Grid) -> Option<Solution> {
+    let mut search = BruteForceSearch::new(grid)?;
+    let mut solutions = Vec::new();
+    search.search(1, &mut solutions);
+    solutions.pop()
+}
+
+// Bitmask backtracking search, always branching on the cell with the fewest candidates
+pub(crate) struct BruteForceSearch {
+    size: usize,
+    values: Vec<u8>,
+    // The candidates the grid still allows for each cell, so earlier eliminations aren't redone
+    allowed: Vec<u32>,
+    layout: Arc<Layout>,
+    // Values used in each group
+    used: Vec<u32>,
+    // How many more placements the search can try before giving up
+    placements_left: usize,
+}
+impl BruteForceSearch {
+    pub(crate) fn new(grid: &Grid) -> Option<BruteForceSearch> {
+        let group_count = grid.layout.groups.len();
+        let mut search = BruteForceSearch {
+            size: grid.size(),
+            values: vec![0; grid.cells.len()],
+            allowed: vec![0; grid.cells.len()],
+            layout: Arc::clone(&grid.layout),
+            used: vec![0; group_count],
+            placements_left: usize::MAX,
+        };
+        for (index, cell) in grid.cells.iter().enumerate() {
+            if cell.value == 0 {
+                search.allowed[index] = cell.candidates;
+                continue;
+            }
+            let bit = 1 << (cell.value - 1);
+            for &group in search.layout.cell_groups[index].iter() {
+                if search.used[group] & bit != 0 {
+                    return None;
+                }
+            }
+            search.place(index, bit);
+        }
+        let mut constraints = search.layout.constraints.iter();
+        if !constraints.all(|constraint| constraint.allows(&search.values)) {
+            return None;
+        }
+        // Constraints can rule out candidates up front that the grid hasn't yet, like the values
+        // that can't make a cage's sum, which saves ruling them out again on every branch
+        for constraint in search.layout.constraints.iter() {
+            let allowed = constraint.restrict(grid);
+            for (&index, &allowed) in constraint.cells().iter().zip(allowed.iter()) {
+                search.allowed[index] &= allowed;
+            }
+        }
+        Some(search)
+    }
+    /// Stops the search after trying the given number of placements
+    pub(crate) fn with_placement_limit(mut self, placements: usize) -> BruteForceSearch {
+        self.placements_left = placements;
+        self
+    }
+    fn place(&mut self, index: usize, bit: u32) {
+        self.values[index] = bit.trailing_zeros() as u8 + 1;
+        for &group in self.layout.cell_groups[index].iter() {
+            self.used[group] |= bit;
+        }
+    }
+    fn unplace(&mut self, index: usize, bit: u32) {
+        self.values[index] = 0;
+        for &group in self.layout.cell_groups[index].iter() {
+            self.used[group] &= !bit;
+        }
+    }
+    // Whether every constraint on the cell still allows the values placed so far
+    fn constraints_allow(&self, index: usize) -> bool {
+        self.layout.cell_constraints[index]
+            .iter()
+            .all(|&constraint| self.layout.constraints[constraint].allows(&self.values))
+    }
+    fn available(&self, index: usize) -> u32 {
+        let mut used = 0;
+        for &group in self.layout.cell_groups[index].iter() {
+            used |= self.used[group];
+        }
+        let mut allowed = self.allowed[index] & !used;
+        for &constraint in self.layout.cell_constraints[index].iter() {
+            allowed &= self.layout.constraints[constraint].allowed_values(&self.values, index);
+        }
+        allowed
+    }
+    // Finds a value that only one cell of a group can still be. A value with nowhere left to go is
+    // returned with nothing to try, as the search is a dead end
+    fn hidden_single(&self) -> Option<(usize, u32)> {
+        let all = (1 << self.size) - 1;
+        for (group_index, group) in self.layout.groups.iter().enumerate() {
+            let (mut once, mut more) = (0, 0);
+            for &index in group.iter().filter(|&&index| self.values[index] == 0) {
+                let available = self.available(index);
+                more |= once & available;
+                once |= available;
+            }
+            if all & !self.used[group_index] & !once != 0 {
+                return Some((group[0], 0));
+            }
+            let single = once & !more;
+            if single != 0 {
+                let bit = single & single.wrapping_neg();
+                let index = group
+                    .iter()
+                    .copied()
+                    .find(|&index| self.values[index] == 0 && self.available(index) & bit != 0)?;
+                return Some((index, bit));
+            }
+        }
+        None
+    }
+    // Searches until `limit` solutions have been found, returns true if the limit was reached, or if
+    // the search ran out of placements to try
+    pub(crate) fn search(&mut self, limit: usize, solutions: &mut Vec<Solution>) -> bool {
+        if self.placements_left == 0 {
+            return true;
+        }
+        self.placements_left -= 1;
+        let mut best: Option<(usize, u32)> = None;
+        for index in 0..self.values.len() {
+            if self.values[index] != 0 {
+                continue;
+            }
+            let available = self.available(index);
+            if available == 0 {
+                return false;
+            }
+            if best.is_none_or(|(_, best)| available.count_ones() < best.count_ones()) {
+                best = Some((index, available));
+                if available.count_ones() == 1 {
+                    break;
+                }
+            }
+        }
+        // A value with only one place left in a group narrows things down just as well, and is
+        // what keeps the search manageable on large boards
+        if best.is_some_and(|(_, available)| available.count_ones() > 1) {
+            best = self.hidden_single().or(best);
+        }
+        let Some((index, mut available)) = best else {
+            let solution = self
+                .values
+                .chunks(self.size)
+                .map(|row| row.to_vec())
+                .collect();
+            solutions.push(solution);
+            return solutions.len() >= limit;
+        };
+        while available != 0 {
+            let bit = available & available.wrapping_neg();
+            available &= !bit;
+            self.place(index, bit);
+            let done = self.constraints_allow(index) && self.search(limit, solutions);
+            self.unplace(index, bit);
+            if done {
+                return true;
+            }
+        }
+        false
+    }
+}
@@ -0,0 +1,424 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::Solver;
+use crate::{GroupType, Position};
+use itertools::Itertools;
+use std::collections::VecDeque;
+
+pub const X_CYCLE: Solver = Solver::new(
+    "X Cycle",
+    "XC",
+    "Followed a chain of a single value alternating between the only two places in a group, and places that see each other, anything that sees both ends of the chain, or both sides of a link in a closed loop, can't be the value",
+    solve_x_cycle,
+    step_x_cycle,
+);
+pub const AIC: Solver = Solver::new(
+    "Alternating Inference Chain",
+    "AI",
+    "Followed a chain alternating between links where one side has to be true, and links where both sides can't be, one end of the chain has to be true, so anything that conflicts with both ends was removed",
+    solve_aic,
+    step_aic,
+);
+pub const GROUPED_AIC: Solver = Solver::new(
+    "Grouped Alternating Inference Chain",
+    "GA",
+    "An Alternating Inference Chain that can also link through a value's cells where a row or column meets a region, anything that conflicts with both ends was removed",
+    solve_grouped_aic,
+    step_grouped_aic,
+);
+pub fn step_x_cycle(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&X_CYCLE);
+    x_cycle(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_x_cycle(grid: &mut Grid) -> Result<bool, SudokuError> {
+    x_cycle(grid, &mut Deduction::new(&X_CYCLE), false)
+}
+pub fn step_aic(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&AIC);
+    let graph = ChainGraph::new(grid, None, false);
+    graph.search(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_aic(grid: &mut Grid) -> Result<bool, SudokuError> {
+    let graph = ChainGraph::new(grid, None, false);
+    graph.search(grid, &mut Deduction::new(&AIC), false)
+}
+pub fn step_grouped_aic(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&GROUPED_AIC);
+    let graph = ChainGraph::new(grid, None, true);
+    graph.search(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_grouped_aic(grid: &mut Grid) -> Result<bool, SudokuError> {
+    let graph = ChainGraph::new(grid, None, true);
+    graph.search(grid, &mut Deduction::new(&GROUPED_AIC), false)
+}
+fn x_cycle(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    for value in 1..=grid.size() as u8 {
+        let graph = ChainGraph::new(grid, Some(value), false);
+        dirty |= graph.search(grid, deduction, single_step)?;
+        if dirty && single_step {
+            return Ok(true);
+        }
+    }
+    Ok(dirty)
+}
+
+// A set of candidates, one bit for each cell * size + value - 1
+type CandidateSet = Vec<u64>;
+const fn candidate_id(size: usize, index: usize, value: u8) -> usize {
+    index * size + value as usize - 1
+}
+
+// A value in one cell, or in a few cells where a row or column meets a region, the node is true if
+// the value is in one of its cells
+struct Node {
+    value: u8,
+    cells: Vec<usize>,
+}
+impl Node {
+    fn is_disjoint(&self, other: &Node) -> bool {
+        !self.cells.iter().any(|cell| other.cells.contains(cell))
+    }
+}
+
+struct ChainGraph {
+    // The size of the board, used to number candidates
+    size: usize,
+    nodes: Vec<Node>,
+    // Links where if one side is false, the other has to be true
+    strong: Vec<Vec<usize>>,
+    // Links where if one side is true, the other has to be false
+    weak: Vec<Vec<usize>>,
+    // Every single cell candidate each node is weakly linked to
+    weak_candidates: Vec<CandidateSet>,
+}
+impl ChainGraph {
+    fn new(grid: &Grid, only_value: Option<u8>, grouped: bool) -> ChainGraph {
+        let allowed = |value: u8| only_value.is_none_or(|only| only == value);
+        let mut nodes = Vec::new();
+        for index in 0..grid.cells.len() {
+            for value in grid.cells[index].get_possibilities() {
+                let value = value as u8;
+                if allowed(value) {
+                    nodes.push(Node {
+                        value,
+                        cells: vec![index],
+                    });
+                }
+            }
+        }
+        if grouped {
+            let groups = &grid.unsolved_groups;
+            let lines = groups[GroupType::Rows as usize]
+                .iter()
+                .chain(groups[GroupType::Columns as usize].iter())
+                .collect::<Vec<&Vec<usize>>>();
+            for region in groups[GroupType::Regions as usize].iter() {
+                for line in lines.iter() {
+                    for value in (1..=grid.size() as u8).filter(|&value| allowed(value)) {
+                        let cells = region
+                            .iter()
+                            .copied()
+                            .filter(|&index| line.contains(&index))
+                            .filter(|&index| grid.cells[index].contains_value(value))
+                            .collect::<Vec<usize>>();
+                        if cells.len() >= 2 {
+                            nodes.push(Node { value, cells });
+                        }
+                    }
+                }
+            }
+        }
+
+        // The nodes each cell is part of, so links are only looked for between nodes in the same
+        // cell or in cells that see each other, rather than between every pair of nodes
+        let mut cell_nodes = vec![Vec::new(); grid.cells.len()];
+        for (i, node) in nodes.iter().enumerate() {
+            for &cell in node.cells.iter() {
+                cell_nodes[cell].push(i);
+            }
+        }
+
+        let mut strong = vec![Vec::new(); nodes.len()];
+        // A value with only two places left in a group, and a cell with only two values left
+        for group in grid.unsolved_groups.iter().flatten() {
+            for value in (1..=grid.size() as u8).filter(|&value| allowed(value)) {
+                let places = group
+                    .iter()
+                    .copied()
+                    .filter(|&index| grid.cells[index].contains_value(value))
+                    .collect::<Vec<usize>>();
+                if places.len() < 2 {
+                    continue;
+                }
+                let mut inside = places
+                    .iter()
+                    .flat_map(|&cell| cell_nodes[cell].iter().copied())
+                    .filter(|&i| nodes[i].value == value)
+                    .filter(|&i| nodes[i].cells.iter().all(|cell| places.contains(cell)))
+                    .collect::<Vec<usize>>();
+                inside.sort_unstable();
+                inside.dedup();
+                for (&a, &b) in inside.iter().tuple_combinations() {
+                    if nodes[a].is_disjoint(&nodes[b])
+                        && nodes[a].cells.len() + nodes[b].cells.len() == places.len()
+                    {
+                        add_link(&mut strong, a, b);
+                    }
+                }
+            }
+        }
+        for (a, node) in nodes.iter().enumerate() {
+            if node.cells.len() != 1 || grid.cells[node.cells[0]].candidates.count_ones() != 2 {
+                continue;
+            }
+            for &b in cell_nodes[node.cells[0]].iter() {
+                if b > a && nodes[b].cells.len() == 1 {
+                    add_link(&mut strong, a, b);
+                }
+            }
+        }
+
+        let mut weak = vec![Vec::new(); nodes.len()];
+        let size = grid.size();
+        let words = (grid.cells.len() * size).div_ceil(64);
+        let mut weak_candidates = vec![vec![0u64; words]; nodes.len()];
+        let linkable = (0..nodes.len()).flat_map(|a| {
+            // A weak link needs the other node to be in the same cell or one that sees it
+            let first = nodes[a].cells[0];
+            let mut others = grid
+                .peers(first)
+                .iter()
+                .chain([&first])
+                .flat_map(|&cell| cell_nodes[cell].iter().copied())
+                .filter(|&b| b > a)
+                .collect::<Vec<usize>>();
+            others.sort_unstable();
+            others.dedup();
+            others.into_iter().map(move |b| (a, b))
+        });
+        for (a, b) in linkable {
+            let (node_a, node_b) = (&nodes[a], &nodes[b]);
+            let is_weak = if node_a.value == node_b.value {
+                node_a.is_disjoint(node_b)
+                    && node_a
+                        .cells
+                        .iter()
+                        .all(|&cell_a| node_b.cells.iter().all(|&cell_b| grid.sees(cell_a, cell_b)))
+            } else {
+                node_a.cells.len() == 1 && node_a.cells == node_b.cells
+            };
+            if !is_weak {
+                continue;
+            }
+            add_link(&mut weak, a, b);
+            if node_b.cells.len() == 1 {
+                let id = candidate_id(size, node_b.cells[0], node_b.value);
+                weak_candidates[a][id / 64] |= 1 << (id % 64);
+            }
+            if node_a.cells.len() == 1 {
+                let id = candidate_id(size, node_a.cells[0], node_a.value);
+                weak_candidates[b][id / 64] |= 1 << (id % 64);
+            }
+        }
+        ChainGraph {
+            size,
+            nodes,
+            strong,
+            weak,
+            weak_candidates,
+        }
+    }
+    // Searches outwards from every node, assuming it is false, and alternately following strong
+    // links to nodes that must then be true and weak links to nodes that must then be false. Every
+    // node reached as true is the other end of a chain where at least one end is true
+    fn search(
+        &self,
+        grid: &mut Grid,
+        deduction: &mut Deduction,
+        single_step: bool,
+    ) -> Result<bool, SudokuError> {
+        let mut dirty = false;
+        for start in 0..self.nodes.len() {
+            // Each state is a node, and whether it is true, the parent is the state it was reached from
+            let mut parents: Vec<Option<usize>> = vec![None; self.nodes.len() * 2];
+            let mut visited = vec![false; self.nodes.len() * 2];
+            let mut queue = VecDeque::from([start * 2]);
+            visited[start * 2] = true;
+            while let Some(state) = queue.pop_front() {
+                let (node, is_true) = (state / 2, state % 2 == 1);
+                let (links, next_true) = if is_true {
+                    (&self.weak[node], 0)
+                } else {
+                    (&self.strong[node], 1)
+                };
+                for &next in links.iter() {
+                    let next_state = next * 2 + next_true;
+                    if visited[next_state] {
+                        continue;
+                    }
+                    visited[next_state] = true;
+                    parents[next_state] = Some(state);
+                    queue.push_back(next_state);
+                    if next_true == 0 {
+                        continue;
+                    }
+                    let mut path = vec![next];
+                    let mut current = next_state;
+                    while let Some(parent) = parents[current] {
+                        path.push(parent / 2);
+                        current = parent;
+                    }
+                    path.reverse();
+                    let (targets, is_loop) = self.find_targets(grid, &path);
+                    let mut removed = Vec::new();
+                    for id in targets {
+                        let (index, value) = (id / self.size, (id % self.size) as u8 + 1);
+                        if deduction.remove_possibility(&mut grid.cells[index], value)? {
+                            removed.push(format!("{}<>{}", grid.position(index), value));
+                        }
+                    }
+                    if removed.is_empty() {
+                        continue;
+                    }
+                    dirty = true;
+                    for &node in path.iter() {
+                        let node = &self.nodes[node];
+                        deduction.add_pattern(&grid.cells, &node.cells, 1 << (node.value - 1));
+                    }
+                    deduction.detail = Some(format!(
+                        "{} => {}",
+                        self.format_chain(grid, &path, is_loop),
+                        removed.join(", ")
+                    ));
+                    if single_step {
+                        return Ok(true);
+                    }
+                }
+            }
+        }
+        Ok(dirty)
+    }
+    // Returns the candidates a chain removes, and whether the chain closes into a loop
+    fn find_targets(&self, grid: &Grid, path: &[usize]) -> (Vec<usize>, bool) {
+        let (start, end) = (path[0], path[path.len() - 1]);
+        let mut targets = vec![0u64; self.weak_candidates.first().map_or(0, Vec::len)];
+        let mut is_loop = false;
+        if start == end {
+            // The start being false forces it to be true, so it has to be true
+            let node = &self.nodes[start];
+            if node.cells.len() == 1 {
+                let index = node.cells[0];
+                for value in grid.cells[index].get_possibilities() {
+                    let value = value as u8;
+                    if value != node.value {
+                        let id = candidate_id(self.size, index, value);
+                        targets[id / 64] |= 1 << (id % 64);
+                    }
+                }
+            }
+        } else {
+            // One of the ends has to be true, so anything weakly linked to both can't be
+            for (word, (a, b)) in targets.iter_mut().zip(
+                self.weak_candidates[start]
+                    .iter()
+                    .zip(self.weak_candidates[end].iter()),
+            ) {
+                *word = a & b;
+            }
+            // If the ends are also weakly linked, the chain is a loop, and every weak link in it
+            // has one true side, so anything weakly linked to both sides of any of them can't be
+            is_loop = self.weak[end].contains(&start) && self.is_simple(path);
+            if is_loop {
+                let closing = [end, start];
+                let weak_links = path[1..path.len() - 1].chunks(2).chain([&closing[..]]);
+                for link in weak_links {
+                    let (a, b) = (
+                        &self.weak_candidates[link[0]],
+                        &self.weak_candidates[link[1]],
+                    );
+                    for (word, (a, b)) in targets.iter_mut().zip(a.iter().zip(b.iter())) {
+                        *word |= a & b;
+                    }
+                }
+                for &node in path.iter() {
+                    let node = &self.nodes[node];
+                    if node.cells.len() == 1 {
+                        let id = candidate_id(self.size, node.cells[0], node.value);
+                        targets[id / 64] &= !(1 << (id % 64));
+                    }
+                }
+            }
+        }
+        let ids = (0..grid.cells.len() * self.size)
+            .filter(|&id| targets[id / 64] & (1 << (id % 64)) != 0)
+            .collect();
+        (ids, is_loop)
+    }
+    // Whether no two nodes of a path share a candidate
+    fn is_simple(&self, path: &[usize]) -> bool {
+        path.iter().tuple_combinations().all(|(&a, &b)| {
+            let (a, b) = (&self.nodes[a], &self.nodes[b]);
+            a.value != b.value || a.is_disjoint(b)
+        })
+    }
+    // Writes a chain in Eureka notation, e.g. (4)r1c2=r1c7-(4)r3c8=r3c1, with bivalue cells as
+    // (4=7)r5c5
+    fn format_chain(&self, grid: &Grid, path: &[usize], is_loop: bool) -> String {
+        let mut chain = path
+            .chunks(2)
+            .map(|pair| {
+                let (a, b) = (&self.nodes[pair[0]], &self.nodes[pair[1]]);
+                if a.value == b.value {
+                    format!(
+                        "({}){}={}",
+                        a.value,
+                        format_cells(grid, a),
+                        format_cells(grid, b)
+                    )
+                } else {
+                    format!("({}={}){}", a.value, b.value, format_cells(grid, a))
+                }
+            })
+            .join("-");
+        if is_loop {
+            let start = &self.nodes[path[0]];
+            chain += &format!("-({}){}", start.value, format_cells(grid, start));
+        }
+        chain
+    }
+}
+fn add_link(links: &mut [Vec<usize>], a: usize, b: usize) {
+    if !links[a].contains(&b) {
+        links[a].push(b);
+        links[b].push(a);
+    }
+}
+// Writes the cells of a node, grouped cells share their row or column, e.g. r1c23, numbers are
+// separated by commas once they can have two digits, e.g. r1c10,11
+fn format_cells(grid: &Grid, node: &Node) -> String {
+    let positions = node
+        .cells
+        .iter()
+        .map(|&index| grid.position(index))
+        .collect::<Vec<Position>>();
+    if positions.len() == 1 {
+        return positions[0].to_string();
+    }
+    let separator = if grid.size() > 9 { "," } else { "" };
+    if positions.iter().all(|pos| pos.row == positions[0].row) {
+        let cols = positions.iter().map(|pos| pos.col + 1).join(separator);
+        format!("r{}c{}", positions[0].row + 1, cols)
+    } else {
+        let rows = positions.iter().map(|pos| pos.row + 1).join(separator);
+        format!("r{}c{}", rows, positions[0].col + 1)
+    }
+}
@@ -0,0 +1,198 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::links::conjugate_pairs;
+use crate::solvers::Solver;
+use itertools::Itertools;
+
+pub const SIMPLE_COLORING: Solver = Solver::new(
+    "Simple Coloring",
+    "SC",
+    "Cells where a value is the only other occurrence in a group were colored alternately, one color has to be the value, so the value was removed from cells that see both colors, or from every cell of a color that sees itself",
+    solve_simple_coloring,
+    step_simple_coloring,
+);
+pub const MULTI_COLORING: Solver = Solver::new(
+    "Multi Coloring",
+    "MC",
+    "Two separate sets of colored cells see each other, so one of the remaining colors has to be the value, and the value was removed from cells that can't be either",
+    solve_multi_coloring,
+    step_multi_coloring,
+);
+pub fn step_simple_coloring(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&SIMPLE_COLORING);
+    simple_coloring(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_simple_coloring(grid: &mut Grid) -> Result<bool, SudokuError> {
+    simple_coloring(grid, &mut Deduction::new(&SIMPLE_COLORING), false)
+}
+pub fn step_multi_coloring(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&MULTI_COLORING);
+    multi_coloring(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_multi_coloring(grid: &mut Grid) -> Result<bool, SudokuError> {
+    multi_coloring(grid, &mut Deduction::new(&MULTI_COLORING), false)
+}
+fn simple_coloring(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    for value in 1..=grid.size() as u8 {
+        for cluster in find_clusters(grid, value) {
+            let mut removed = false;
+            // Color wrap: two cells of the same color see each other, so that color can't be the
+            // value, and the other color has to be
+            for color in cluster.iter() {
+                let wrapped = color
+                    .iter()
+                    .tuple_combinations()
+                    .any(|(&a, &b)| grid.sees(a, b));
+                if wrapped {
+                    for &index in color.iter() {
+                        removed |= deduction.remove_possibility(&mut grid.cells[index], value)?;
+                    }
+                }
+            }
+            // Color trap: a cell that sees both colors can't be the value
+            if !removed {
+                for index in 0..grid.cells.len() {
+                    if cluster[0].contains(&index) || cluster[1].contains(&index) {
+                        continue;
+                    }
+                    if sees_any(grid, index, &cluster[0]) && sees_any(grid, index, &cluster[1]) {
+                        removed |= deduction.remove_possibility(&mut grid.cells[index], value)?;
+                    }
+                }
+            }
+            if !removed {
+                continue;
+            }
+            dirty = true;
+            deduction.add_pattern(&grid.cells, &cluster[0], 1 << (value - 1));
+            deduction.add_pattern(&grid.cells, &cluster[1], 1 << (value - 1));
+            deduction.detail = Some(format!(
+                "Value {} colored {} one way, and {} the other",
+                value,
+                format_cells(grid, &cluster[0]),
+                format_cells(grid, &cluster[1])
+            ));
+            if single_step {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(dirty)
+}
+fn multi_coloring(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    for value in 1..=grid.size() as u8 {
+        let clusters = find_clusters(grid, value);
+        for (first, second) in clusters.iter().tuple_combinations() {
+            for (color_a, color_b) in (0..2).cartesian_product(0..2) {
+                let a = &first[color_a];
+                let b = &second[color_b];
+                if !a.iter().any(|&index| sees_any(grid, index, b)) {
+                    continue;
+                }
+                // Color A of the first cluster and color B of the second can't both be the value,
+                // so the opposite color of at least one of them is
+                let mut removed = false;
+                let a_opposite = &first[1 - color_a];
+                let b_opposite = &second[1 - color_b];
+                // Wrap: color A also sees the opposite of B, so A can't be the value either way
+                if a.iter().any(|&index| sees_any(grid, index, b_opposite)) {
+                    for &index in a.iter() {
+                        removed |= deduction.remove_possibility(&mut grid.cells[index], value)?;
+                    }
+                }
+                // Trap: anything that sees both opposite colors can't be the value
+                if !removed {
+                    for index in 0..grid.cells.len() {
+                        if a_opposite.contains(&index) || b_opposite.contains(&index) {
+                            continue;
+                        }
+                        if sees_any(grid, index, a_opposite) && sees_any(grid, index, b_opposite) {
+                            removed |=
+                                deduction.remove_possibility(&mut grid.cells[index], value)?;
+                        }
+                    }
+                }
+                if !removed {
+                    continue;
+                }
+                dirty = true;
+                for color in [a, a_opposite, b, b_opposite] {
+                    deduction.add_pattern(&grid.cells, color, 1 << (value - 1));
+                }
+                deduction.detail = Some(format!(
+                    "Value {} colored {} one way and {} the other, and separately {} one way and {} the other",
+                    value,
+                    format_cells(grid, a),
+                    format_cells(grid, a_opposite),
+                    format_cells(grid, b),
+                    format_cells(grid, b_opposite)
+                ));
+                if single_step {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+    Ok(dirty)
+}
+// Splits the conjugate pairs of a value into connected clusters, each colored in two alternating
+// colors, exactly one of which has to be the value
+fn find_clusters(grid: &Grid, value: u8) -> Vec<[Vec<usize>; 2]> {
+    let pairs = conjugate_pairs(grid, value)
+        .into_iter()
+        .map(|pair| (pair.cells[0], pair.cells[1]))
+        .collect::<Vec<(usize, usize)>>();
+    let mut colors: Vec<Option<usize>> = vec![None; grid.cells.len()];
+    let mut clusters = Vec::new();
+    for &(start, _) in pairs.iter() {
+        if colors[start].is_some() {
+            continue;
+        }
+        let mut cluster: [Vec<usize>; 2] = [vec![start], Vec::new()];
+        colors[start] = Some(0);
+        let mut queue = vec![start];
+        while let Some(index) = queue.pop() {
+            let color = colors[index].unwrap();
+            for &(a, b) in pairs.iter() {
+                let other = if a == index {
+                    b
+                } else if b == index {
+                    a
+                } else {
+                    continue;
+                };
+                if colors[other].is_none() {
+                    colors[other] = Some(1 - color);
+                    cluster[1 - color].push(other);
+                    queue.push(other);
+                }
+            }
+        }
+        cluster[0].sort();
+        cluster[1].sort();
+        clusters.push(cluster);
+    }
+    clusters
+}
+fn sees_any(grid: &Grid, index: usize, cells: &[usize]) -> bool {
+    cells.iter().any(|&other| grid.sees(index, other))
+}
+fn format_cells(grid: &Grid, cells: &[usize]) -> String {
+    cells
+        .iter()
+        .map(|&index| grid.position(index).to_string())
+        .join(", ")
+}
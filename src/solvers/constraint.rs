@@ -0,0 +1,51 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::Solver;
+
+pub const CONSTRAINT: Solver = Solver::new(
+    "Constraint",
+    "CO",
+    "A rule the variant adds on top of the houses ruled out values, like values that can't add up to a cage's sum",
+    solve_constraint,
+    step_constraint,
+);
+pub fn step_constraint(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&CONSTRAINT);
+    constraint(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_constraint(grid: &mut Grid) -> Result<bool, SudokuError> {
+    constraint(grid, &mut Deduction::new(&CONSTRAINT), false)
+}
+fn constraint(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    for constraint in grid.constraints().to_vec() {
+        let allowed = constraint.restrict(grid);
+        let mut removed = 0;
+        for (&index, &allowed) in constraint.cells().iter().zip(allowed.iter()) {
+            let cell = &mut grid.cells[index];
+            if cell.value != 0 {
+                continue;
+            }
+            let ruled_out = cell.candidates & !allowed;
+            if deduction.remove_possibilities(cell, ruled_out)? {
+                removed |= ruled_out;
+            }
+        }
+        if removed == 0 {
+            continue;
+        }
+        dirty = true;
+        deduction.add_pattern(&grid.cells, constraint.cells(), removed);
+        deduction.detail = Some(constraint.to_string());
+        if single_step {
+            return Ok(true);
+        }
+    }
+    Ok(dirty)
+}
@@ -0,0 +1,104 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::als::{find_als, peer_masks, remove_from_cells, seen_by_all, Als, CellSet};
+use crate::solvers::Solver;
+use itertools::Itertools;
+
+pub const DEATH_BLOSSOM: Solver = Solver::new(
+    "Death Blossom",
+    "DB",
+    "Each value of a stem cell is seen by every place it could go in its own Almost Locked Set, whichever value the stem is, that set is locked, so a value Z all the sets share has to be in one of them, and Z was removed from anything that sees all of those places",
+    solve_death_blossom,
+    step_death_blossom,
+);
+pub fn step_death_blossom(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&DEATH_BLOSSOM);
+    death_blossom(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_death_blossom(grid: &mut Grid) -> Result<bool, SudokuError> {
+    death_blossom(grid, &mut Deduction::new(&DEATH_BLOSSOM), false)
+}
+// The most values a stem cell can have, more petals makes the search too slow to be worth it
+const MAX_PETALS: u32 = 3;
+fn death_blossom(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    let sets = find_als(grid);
+    let peers = peer_masks(grid);
+    for stem in 0..grid.cells.len() {
+        let stem_candidates = grid.cells[stem].candidates;
+        if grid.cells[stem].value != 0 || stem_candidates.count_ones() > MAX_PETALS {
+            continue;
+        }
+        let values = grid.cells[stem]
+            .get_possibilities()
+            .into_iter()
+            .map(|value| value as u8)
+            .collect::<Vec<u8>>();
+        // For each value of the stem, every set where all the places for it see the stem
+        let petals = values
+            .iter()
+            .map(|&value| {
+                sets.iter()
+                    .filter(|als| !als.cells.contains(stem) && als.has_value(value))
+                    .filter(|als| (als.value_cells[value as usize - 1] & !peers[stem]).is_empty())
+                    .collect::<Vec<&Als>>()
+            })
+            .collect::<Vec<Vec<&Als>>>();
+        for blossom in petals.iter().multi_cartesian_product() {
+            let disjoint = blossom
+                .iter()
+                .tuple_combinations()
+                .all(|(a, b)| (a.cells & b.cells).is_empty());
+            // Z can't be one of the stem's values, or the petal for it wouldn't have to hold Z
+            let shared = blossom
+                .iter()
+                .fold(!stem_candidates, |acc, als| acc & als.candidates);
+            if !disjoint || shared == 0 {
+                continue;
+            }
+            let all_cells = blossom
+                .iter()
+                .fold(CellSet::single(stem), |acc, als| acc | als.cells);
+            let mut removed = Vec::new();
+            for z in (0..grid.size()).filter(|&bit| shared & (1 << bit) != 0) {
+                let z_cells = blossom
+                    .iter()
+                    .fold(CellSet::default(), |acc, als| acc | als.value_cells[z]);
+                let targets = seen_by_all(&peers, z_cells) & !all_cells;
+                if remove_from_cells(grid, deduction, z as u8 + 1, targets)? {
+                    removed.push(z + 1);
+                }
+            }
+            if removed.is_empty() {
+                continue;
+            }
+            dirty = true;
+            let cells = (all_cells).iter().collect::<Vec<usize>>();
+            let blossom_values = blossom
+                .iter()
+                .fold(stem_candidates, |acc, als| acc | als.candidates);
+            deduction.add_pattern(&grid.cells, &cells, blossom_values);
+            let petals = values
+                .iter()
+                .zip(blossom.iter())
+                .map(|(value, als)| format!("{}: {}", value, als.describe(grid)))
+                .join(", ");
+            deduction.detail = Some(format!(
+                "Stem {} with petals {}, removed {}",
+                grid.position(stem),
+                petals,
+                removed.iter().join(", ")
+            ));
+            if single_step {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(dirty)
+}
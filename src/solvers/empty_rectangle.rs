@@ -0,0 +1,115 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::links::conjugate_pairs;
+use crate::solvers::Solver;
+use crate::{GroupType, Position};
+use itertools::Itertools;
+
+pub const EMPTY_RECTANGLE: Solver = Solver::new(
+    "Empty Rectangle",
+    "ER",
+    "A value in a region only shows up on one row and one column, a line where the value only shows up twice ends on one of them, whichever end is the value, the cell where the other end's line crosses the region's other line can't be",
+    solve_empty_rectangle,
+    step_empty_rectangle,
+);
+pub fn step_empty_rectangle(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&EMPTY_RECTANGLE);
+    empty_rectangle(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_empty_rectangle(grid: &mut Grid) -> Result<bool, SudokuError> {
+    empty_rectangle(grid, &mut Deduction::new(&EMPTY_RECTANGLE), false)
+}
+fn empty_rectangle(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    for value in 1..=grid.size() as u8 {
+        let pairs = conjugate_pairs(grid, value);
+        let regions = grid.unsolved_groups[GroupType::Regions as usize].clone();
+        for (region, region_cells) in regions.iter().enumerate() {
+            let cells = region_cells
+                .iter()
+                .filter(|&&index| grid.cells[index].contains_value(value))
+                .map(|&index| grid.position(index))
+                .collect::<Vec<Position>>();
+            if cells.len() < 2 {
+                continue;
+            }
+            let region_rows = cells
+                .iter()
+                .map(|pos| pos.row)
+                .unique()
+                .collect::<Vec<usize>>();
+            let region_cols = cells
+                .iter()
+                .map(|pos| pos.col)
+                .unique()
+                .collect::<Vec<usize>>();
+            for &row in region_rows.iter() {
+                for &col in region_cols.iter() {
+                    // Every occurrence has to be on the cross, without all being on one line of it
+                    if cells.iter().any(|pos| pos.row != row && pos.col != col)
+                        || cells.iter().all(|pos| pos.row == row)
+                        || cells.iter().all(|pos| pos.col == col)
+                    {
+                        continue;
+                    }
+                    for pair in pairs.iter() {
+                        for (near, far) in [(0, 1), (1, 0)] {
+                            let near = grid.position(pair.cells[near]);
+                            let far = grid.position(pair.cells[far]);
+                            // The near end lines up with the cross, outside the region, and the
+                            // target where the far end's line crosses the region's other line is
+                            // outside the region too
+                            let outside = |pos: Position| grid.region(grid.index(pos)) != region;
+                            let target = match pair.group_type {
+                                GroupType::Columns
+                                    if near.row == row && near.col != col && outside(near) =>
+                                {
+                                    Position::new(far.row, col)
+                                }
+                                GroupType::Rows
+                                    if near.col == col && near.row != row && outside(near) =>
+                                {
+                                    Position::new(row, far.col)
+                                }
+                                _ => continue,
+                            };
+                            if !outside(target) {
+                                continue;
+                            }
+                            let target = grid.index(target);
+                            if !deduction.remove_possibility(&mut grid.cells[target], value)? {
+                                continue;
+                            }
+                            dirty = true;
+                            let pattern = cells
+                                .iter()
+                                .map(|&pos| grid.index(pos))
+                                .chain(pair.cells)
+                                .collect::<Vec<usize>>();
+                            deduction.add_pattern(&grid.cells, &pattern, 1 << (value - 1));
+                            deduction.detail = Some(format!(
+                                "Value {} in region {} is on row {} or column {}, and {} or {} has to be it",
+                                value,
+                                region + 1,
+                                row + 1,
+                                col + 1,
+                                near,
+                                far
+                            ));
+                            if single_step {
+                                return Ok(true);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(dirty)
+}
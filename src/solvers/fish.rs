@@ -0,0 +1,368 @@
+use crate::cell::Cell;
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::Solver;
+use crate::GroupType;
+use itertools::Itertools;
+
+pub const X_WING: Solver = Solver::new(
+    "X Wing",
+    "XW",
+    "If a value occurs only twice in a line, and occurs in the same index in a second line, then it can't be anywhere else in that index",
+    solve_x_wing,
+    step_x_wing,
+);
+pub const SWORDFISH: Solver = Solver::new(
+    "Swordfish",
+    "SF",
+    "If a value in three lines only occurs within the same three indices, then it can't be anywhere else in those indices",
+    solve_swordfish,
+    step_swordfish,
+);
+pub const JELLYFISH: Solver = Solver::new(
+    "Jellyfish",
+    "JF",
+    "If a value in four lines only occurs within the same four indices, then it can't be anywhere else in those indices",
+    solve_jellyfish,
+    step_jellyfish,
+);
+pub const FINNED_X_WING: Solver = Solver::new(
+    "Finned X Wing",
+    "FX",
+    "An X Wing, except one line has extra occurrences (fins) in a single region, either a fin is the value, or the X Wing is, so the value was removed from anything that sees both",
+    solve_finned_x_wing,
+    step_finned_x_wing,
+);
+pub const FINNED_SWORDFISH: Solver = Solver::new(
+    "Finned Swordfish",
+    "FS",
+    "A Swordfish, except the lines have extra occurrences (fins) in a single region, either a fin is the value, or the Swordfish is, so the value was removed from anything that sees both",
+    solve_finned_swordfish,
+    step_finned_swordfish,
+);
+pub const FINNED_JELLYFISH: Solver = Solver::new(
+    "Finned Jellyfish",
+    "FJ",
+    "A Jellyfish, except the lines have extra occurrences (fins) in a single region, either a fin is the value, or the Jellyfish is, so the value was removed from anything that sees both",
+    solve_finned_jellyfish,
+    step_finned_jellyfish,
+);
+pub const SASHIMI_X_WING: Solver = Solver::new(
+    "Sashimi X Wing",
+    "SX",
+    "A Finned X Wing where a line only has one occurrence besides its fins, either a fin is the value, or the X Wing is, so the value was removed from anything that sees both",
+    solve_sashimi_x_wing,
+    step_sashimi_x_wing,
+);
+pub const SASHIMI_SWORDFISH: Solver = Solver::new(
+    "Sashimi Swordfish",
+    "SS",
+    "A Finned Swordfish where a line only has one occurrence besides its fins, either a fin is the value, or the Swordfish is, so the value was removed from anything that sees both",
+    solve_sashimi_swordfish,
+    step_sashimi_swordfish,
+);
+pub const SASHIMI_JELLYFISH: Solver = Solver::new(
+    "Sashimi Jellyfish",
+    "SJ",
+    "A Finned Jellyfish where a line only has one occurrence besides its fins, either a fin is the value, or the Jellyfish is, so the value was removed from anything that sees both",
+    solve_sashimi_jellyfish,
+    step_sashimi_jellyfish,
+);
+#[derive(Copy, Clone, PartialEq)]
+enum FinType {
+    Finned,
+    Sashimi,
+}
+pub fn step_x_wing(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    step_fish(grid, &X_WING, 2)
+}
+pub fn solve_x_wing(grid: &mut Grid) -> Result<bool, SudokuError> {
+    solve_fish(grid, &X_WING, 2)
+}
+pub fn step_swordfish(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    step_fish(grid, &SWORDFISH, 3)
+}
+pub fn solve_swordfish(grid: &mut Grid) -> Result<bool, SudokuError> {
+    solve_fish(grid, &SWORDFISH, 3)
+}
+pub fn step_jellyfish(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    step_fish(grid, &JELLYFISH, 4)
+}
+pub fn solve_jellyfish(grid: &mut Grid) -> Result<bool, SudokuError> {
+    solve_fish(grid, &JELLYFISH, 4)
+}
+pub fn step_finned_x_wing(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    step_finned_fish(grid, &FINNED_X_WING, 2, FinType::Finned)
+}
+pub fn solve_finned_x_wing(grid: &mut Grid) -> Result<bool, SudokuError> {
+    solve_finned_fish(grid, &FINNED_X_WING, 2, FinType::Finned)
+}
+pub fn step_sashimi_x_wing(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    step_finned_fish(grid, &SASHIMI_X_WING, 2, FinType::Sashimi)
+}
+pub fn solve_sashimi_x_wing(grid: &mut Grid) -> Result<bool, SudokuError> {
+    solve_finned_fish(grid, &SASHIMI_X_WING, 2, FinType::Sashimi)
+}
+pub fn step_finned_swordfish(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    step_finned_fish(grid, &FINNED_SWORDFISH, 3, FinType::Finned)
+}
+pub fn solve_finned_swordfish(grid: &mut Grid) -> Result<bool, SudokuError> {
+    solve_finned_fish(grid, &FINNED_SWORDFISH, 3, FinType::Finned)
+}
+pub fn step_sashimi_swordfish(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    step_finned_fish(grid, &SASHIMI_SWORDFISH, 3, FinType::Sashimi)
+}
+pub fn solve_sashimi_swordfish(grid: &mut Grid) -> Result<bool, SudokuError> {
+    solve_finned_fish(grid, &SASHIMI_SWORDFISH, 3, FinType::Sashimi)
+}
+pub fn step_finned_jellyfish(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    step_finned_fish(grid, &FINNED_JELLYFISH, 4, FinType::Finned)
+}
+pub fn solve_finned_jellyfish(grid: &mut Grid) -> Result<bool, SudokuError> {
+    solve_finned_fish(grid, &FINNED_JELLYFISH, 4, FinType::Finned)
+}
+pub fn step_sashimi_jellyfish(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    step_finned_fish(grid, &SASHIMI_JELLYFISH, 4, FinType::Sashimi)
+}
+pub fn solve_sashimi_jellyfish(grid: &mut Grid) -> Result<bool, SudokuError> {
+    solve_finned_fish(grid, &SASHIMI_JELLYFISH, 4, FinType::Sashimi)
+}
+fn step_fish(
+    grid: &mut Grid,
+    technique: &Solver,
+    size: usize,
+) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(technique);
+    for line_type in [GroupType::Rows, GroupType::Columns] {
+        let lines = &grid.unsolved_groups[line_type as usize];
+        if fish_group(&mut grid.cells, &mut deduction, lines, size, true)? {
+            break;
+        }
+    }
+    Ok(deduction.finish())
+}
+fn solve_fish(grid: &mut Grid, technique: &Solver, size: usize) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    let mut deduction = Deduction::new(technique);
+    for line_type in [GroupType::Rows, GroupType::Columns] {
+        let lines = &grid.unsolved_groups[line_type as usize];
+        dirty |= fish_group(&mut grid.cells, &mut deduction, lines, size, false)?;
+    }
+    Ok(dirty)
+}
+// Looks for `size` base lines where a value only occurs within the same `size` indices, and
+// removes that value from those indices in every other line
+fn fish_group(
+    cells: &mut [Cell],
+    deduction: &mut Deduction,
+    line_collection: &[Vec<usize>],
+    size: usize,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+
+    // Iterate over numbers
+    for n in 1..=line_collection.len() as u8 {
+        let occurrences = line_collection
+            .iter()
+            .map(|line| find_occurrences(cells, line, n))
+            .collect::<Vec<u32>>();
+        // Lines where the value occurs too often can't be part of the fish
+        let base_lines = (0..line_collection.len())
+            .filter(|&i| occurrences[i] != 0 && occurrences[i].count_ones() as usize <= size)
+            .collect::<Vec<usize>>();
+        for base in base_lines.into_iter().combinations(size) {
+            let cover = base.iter().fold(0u32, |acc, &i| acc | occurrences[i]);
+            if cover.count_ones() as usize != size {
+                continue;
+            }
+            // We have a fish in the base lines, so the value is removed from the cover indices
+            // in every other line
+            let mut removed = false;
+            for (i, line) in line_collection.iter().enumerate() {
+                if base.contains(&i) {
+                    continue;
+                }
+                for (j, &index) in line.iter().enumerate() {
+                    if cover & (1 << j) != 0 {
+                        removed |= deduction.remove_possibility(&mut cells[index], n)?;
+                    }
+                }
+            }
+            if removed {
+                let fish_cells = base
+                    .iter()
+                    .flat_map(|&i| line_collection[i].iter().copied())
+                    .filter(|&index| cells[index].contains_value(n))
+                    .collect::<Vec<usize>>();
+                deduction.add_pattern(cells, &fish_cells, 1 << (n - 1));
+            }
+            dirty |= removed;
+            if dirty && single_step {
+                return Ok(true);
+            }
+        }
+    }
+
+    Ok(dirty)
+}
+fn step_finned_fish(
+    grid: &mut Grid,
+    technique: &Solver,
+    size: usize,
+    fin_type: FinType,
+) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(technique);
+    for line_type in [GroupType::Rows, GroupType::Columns] {
+        if finned_fish_group(grid, &mut deduction, line_type, size, fin_type, true)? {
+            break;
+        }
+    }
+    Ok(deduction.finish())
+}
+fn solve_finned_fish(
+    grid: &mut Grid,
+    technique: &Solver,
+    size: usize,
+    fin_type: FinType,
+) -> Result<bool, SudokuError> {
+    let mut deduction = Deduction::new(technique);
+    let mut dirty = false;
+    dirty |= finned_fish_group(grid, &mut deduction, GroupType::Rows, size, fin_type, false)?;
+    dirty |= finned_fish_group(
+        grid,
+        &mut deduction,
+        GroupType::Columns,
+        size,
+        fin_type,
+        false,
+    )?;
+    Ok(dirty)
+}
+// Looks for `size` base lines where a value only occurs within the same `size` indices, apart from
+// some extra occurrences (fins) that are all in one region. Either a fin is the value, or the fish
+// is, so the value is removed from the cover indices of other lines that are also in the fins'
+// region
+fn finned_fish_group(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    line_type: GroupType,
+    size: usize,
+    fin_type: FinType,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+
+    let line_collection = grid.unsolved_groups[line_type as usize].clone();
+    let line_name = match line_type {
+        GroupType::Rows => "rows",
+        _ => "columns",
+    };
+    // The most indices along the line that a region spans, which jigsaw regions can stretch
+    let max_fins = grid.unsolved_groups[GroupType::Regions as usize]
+        .iter()
+        .map(|region| {
+            region
+                .iter()
+                .map(|&index| match line_type {
+                    GroupType::Rows => grid.position(index).col,
+                    _ => grid.position(index).row,
+                })
+                .unique()
+                .count()
+        })
+        .max()
+        .unwrap_or(0);
+    for n in 1..=grid.size() as u8 {
+        let occurrences = line_collection
+            .iter()
+            .map(|line| find_occurrences(&grid.cells, line, n))
+            .collect::<Vec<u32>>();
+        // Fins all have to be in one region, so they can't take up more indices than a region
+        // spans along the line
+        let base_lines = (0..line_collection.len())
+            .filter(|&i| {
+                occurrences[i] != 0 && occurrences[i].count_ones() as usize <= size + max_fins
+            })
+            .collect::<Vec<usize>>();
+        for base in base_lines.into_iter().combinations(size) {
+            let all = base.iter().fold(0u32, |acc, &i| acc | occurrences[i]);
+            let count = all.count_ones() as usize;
+            // Without any extra indices this is a regular fish
+            if count <= size || count > size + max_fins {
+                continue;
+            }
+            let indices = (0..line_collection.len()).filter(|&j| all & (1 << j) != 0);
+            for cover in indices.combinations(size) {
+                let cover = cover.iter().fold(0u32, |acc, &j| acc | (1 << j));
+                if base.iter().any(|&i| occurrences[i] & cover == 0) {
+                    continue;
+                }
+                let is_sashimi = base
+                    .iter()
+                    .any(|&i| (occurrences[i] & cover).count_ones() == 1);
+                if is_sashimi != (fin_type == FinType::Sashimi) {
+                    continue;
+                }
+                let mut fins = Vec::new();
+                for &i in base.iter() {
+                    for (j, &index) in line_collection[i].iter().enumerate() {
+                        if occurrences[i] & !cover & (1 << j) != 0 {
+                            fins.push(index);
+                        }
+                    }
+                }
+                let fin_region = grid.region(fins[0]);
+                if fins.iter().any(|&fin| grid.region(fin) != fin_region) {
+                    continue;
+                }
+                let mut removed = false;
+                for (i, line) in line_collection.iter().enumerate() {
+                    if base.contains(&i) {
+                        continue;
+                    }
+                    for (j, &index) in line.iter().enumerate() {
+                        if cover & (1 << j) != 0 && grid.region(index) == fin_region {
+                            removed |= deduction.remove_possibility(&mut grid.cells[index], n)?;
+                        }
+                    }
+                }
+                if !removed {
+                    continue;
+                }
+                dirty = true;
+                let fish_cells = base
+                    .iter()
+                    .flat_map(|&i| line_collection[i].iter().copied())
+                    .filter(|&index| grid.cells[index].contains_value(n))
+                    .collect::<Vec<usize>>();
+                deduction.add_pattern(&grid.cells, &fish_cells, 1 << (n - 1));
+                let lines = base.iter().map(|i| (i + 1).to_string()).join(", ");
+                let fins = fins
+                    .iter()
+                    .map(|&fin| grid.position(fin).to_string())
+                    .join(", ");
+                deduction.detail = Some(format!(
+                    "Value {} in {} {}, with fins at {}",
+                    n, line_name, lines, fins
+                ));
+                if single_step {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+
+    Ok(dirty)
+}
+// Returns a bitset of the indices in the line that could be the value
+fn find_occurrences(cells: &[Cell], line: &[usize], value: u8) -> u32 {
+    let mut found_indices = 0u32;
+    for (c, &index) in line.iter().enumerate() {
+        if cells[index].contains_value(value) {
+            found_indices |= 1 << c;
+        }
+    }
+    found_indices
+}
@@ -0,0 +1,281 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::{step, Solver, CONSTRAINT, HIDDEN_SINGLE, LOCKED_CANDIDATES, NAKED_SINGLE};
+use itertools::Itertools;
+
+pub const CELL_FORCING_CHAIN: Solver = Solver::new(
+    "Cell Forcing Chain",
+    "CF",
+    "Tried every value of a cell and followed what each one forces, anything that happens no matter which value the cell is was applied",
+    solve_cell_forcing_chain,
+    step_cell_forcing_chain,
+);
+pub const UNIT_FORCING_CHAIN: Solver = Solver::new(
+    "Unit Forcing Chain",
+    "UF",
+    "Tried every place a value can go in a group and followed what each one forces, anything that happens no matter where the value goes was applied",
+    solve_unit_forcing_chain,
+    step_unit_forcing_chain,
+);
+pub const NISHIO: Solver = Solver::new(
+    "Nishio",
+    "NI",
+    "Assumed a cell was a value and followed what it forces, that led to a contradiction, so the value was removed from the cell",
+    solve_nishio,
+    step_nishio,
+);
+// The solvers used to follow an assumption through the board
+const PROPAGATION_SOLVERS: [&Solver; 4] = [
+    &NAKED_SINGLE,
+    &HIDDEN_SINGLE,
+    &CONSTRAINT,
+    &LOCKED_CANDIDATES,
+];
+// The most steps to follow an assumption for, following every one to the end is far too slow on
+// large boards, and a long chain doesn't make much of a hint anyway
+const MAX_CHAIN_STEPS: usize = 30;
+
+pub fn step_cell_forcing_chain(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&CELL_FORCING_CHAIN);
+    cell_forcing_chain(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_cell_forcing_chain(grid: &mut Grid) -> Result<bool, SudokuError> {
+    cell_forcing_chain(grid, &mut Deduction::new(&CELL_FORCING_CHAIN), false)
+}
+pub fn step_unit_forcing_chain(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&UNIT_FORCING_CHAIN);
+    unit_forcing_chain(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_unit_forcing_chain(grid: &mut Grid) -> Result<bool, SudokuError> {
+    unit_forcing_chain(grid, &mut Deduction::new(&UNIT_FORCING_CHAIN), false)
+}
+pub fn step_nishio(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&NISHIO);
+    nishio(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_nishio(grid: &mut Grid) -> Result<bool, SudokuError> {
+    nishio(grid, &mut Deduction::new(&NISHIO), false)
+}
+
+// The result of assuming a cell is a value, and following what it forces
+struct Trial {
+    // None if the assumption led to a contradiction
+    grid: Option<Grid>,
+    // Each cell filled in along the way, in order, ending with the contradiction if there was one
+    chain: Vec<String>,
+}
+fn assume(grid: &Grid, index: usize, value: u8) -> Result<Trial, SudokuError> {
+    let mut trial = grid.clone();
+    trial.auto_promote = false;
+    // A wrong assumption should end in a contradiction, not a mismatch with the known answer
+    for cell in trial.cells.iter_mut() {
+        cell.answer = None;
+    }
+    let mut chain = vec![format!("{}={}", grid.position(index), value)];
+    match propagate(&mut trial, index, value, &mut chain) {
+        Ok(()) => Ok(Trial {
+            grid: Some(trial),
+            chain,
+        }),
+        Err(error) => {
+            chain.push(error.to_string());
+            Ok(Trial { grid: None, chain })
+        }
+    }
+}
+fn propagate(
+    grid: &mut Grid,
+    index: usize,
+    value: u8,
+    chain: &mut Vec<String>,
+) -> Result<(), SudokuError> {
+    grid.set_cell(grid.position(index), value)?;
+    for _ in 0..MAX_CHAIN_STEPS {
+        let Some(deduction) = step(grid, &PROPAGATION_SOLVERS)? else {
+            break;
+        };
+        for (position, value) in deduction.placements {
+            chain.push(format!(
+                "{}={} ({})",
+                position, value, deduction.technique.abbreviation
+            ));
+        }
+    }
+    // A value with nowhere left to go in a group is as much a contradiction as an empty cell
+    for group in grid.unsolved_groups.iter().flatten() {
+        let found = group.iter().fold(0u32, |acc, &index| {
+            let cell = &grid.cells[index];
+            match cell.value {
+                0 => acc | cell.candidates,
+                value => acc | (1 << (value - 1)),
+            }
+        });
+        if found != grid.all_candidates() {
+            return Err(SudokuError::NoSolution);
+        }
+    }
+    let values = grid
+        .cells
+        .iter()
+        .map(|cell| cell.value)
+        .collect::<Vec<u8>>();
+    if !grid
+        .constraints()
+        .iter()
+        .all(|constraint| constraint.allows(&values))
+    {
+        return Err(SudokuError::NoSolution);
+    }
+    Ok(())
+}
+// Removes every candidate that none of the branches left possible, returns the cells that changed
+fn apply_common(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    branches: &[Trial],
+) -> Result<Vec<usize>, SudokuError> {
+    let mut changed = Vec::new();
+    let Some(branch_grids) = branches
+        .iter()
+        .map(|trial| trial.grid.as_ref())
+        .collect::<Option<Vec<&Grid>>>()
+    else {
+        // A branch that breaks the board is left for Nishio to deal with
+        return Ok(changed);
+    };
+    for index in 0..grid.cells.len() {
+        if grid.cells[index].value != 0 {
+            continue;
+        }
+        let possible = branch_grids.iter().fold(0u32, |acc, branch| {
+            let cell = &branch.cells[index];
+            match cell.value {
+                0 => acc | cell.candidates,
+                value => acc | (1 << (value - 1)),
+            }
+        });
+        let cell = &mut grid.cells[index];
+        let impossible = cell.candidates & !possible;
+        if deduction.remove_possibilities(cell, impossible)? {
+            changed.push(index);
+        }
+    }
+    Ok(changed)
+}
+fn format_branches(grid: &Grid, branches: &[Trial], changed: &[usize]) -> String {
+    let mut detail = branches
+        .iter()
+        .map(|trial| trial.chain.join(" -> "))
+        .join("\n");
+    let changed = changed
+        .iter()
+        .map(|&index| grid.position(index).to_string())
+        .join(", ");
+    detail += &format!("\nEvery branch agrees on {}", changed);
+    detail
+}
+fn cell_forcing_chain(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    for index in 0..grid.cells.len() {
+        if grid.cells[index].value != 0 {
+            continue;
+        }
+        let candidates = grid.cells[index].candidates;
+        let branches = grid.cells[index]
+            .get_possibilities()
+            .into_iter()
+            .map(|value| assume(grid, index, value as u8))
+            .collect::<Result<Vec<Trial>, SudokuError>>()?;
+        let changed = apply_common(grid, deduction, &branches)?;
+        if changed.is_empty() {
+            continue;
+        }
+        dirty = true;
+        deduction.add_pattern(&grid.cells, &[index], candidates);
+        deduction.detail = Some(format!(
+            "Trying every value of {}:\n{}",
+            grid.position(index),
+            format_branches(grid, &branches, &changed)
+        ));
+        if single_step {
+            return Ok(true);
+        }
+    }
+    Ok(dirty)
+}
+fn unit_forcing_chain(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    let groups = grid.unsolved_groups.clone();
+    for group in groups.iter().flatten() {
+        for value in 1..=grid.size() as u8 {
+            let places = group
+                .iter()
+                .copied()
+                .filter(|&index| grid.cells[index].contains_value(value))
+                .collect::<Vec<usize>>();
+            // With only one place left, this is just a hidden single
+            if places.len() < 2 {
+                continue;
+            }
+            let branches = places
+                .iter()
+                .map(|&index| assume(grid, index, value))
+                .collect::<Result<Vec<Trial>, SudokuError>>()?;
+            let changed = apply_common(grid, deduction, &branches)?;
+            if changed.is_empty() {
+                continue;
+            }
+            dirty = true;
+            deduction.add_pattern(&grid.cells, &places, 1 << (value - 1));
+            let places = places
+                .iter()
+                .map(|&index| grid.position(index).to_string())
+                .join(", ");
+            deduction.detail = Some(format!(
+                "Trying value {} in each of {}:\n{}",
+                value,
+                places,
+                format_branches(grid, &branches, &changed)
+            ));
+            if single_step {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(dirty)
+}
+fn nishio(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    for index in 0..grid.cells.len() {
+        for value in grid.cells[index].get_possibilities() {
+            let value = value as u8;
+            let trial = assume(grid, index, value)?;
+            if trial.grid.is_some() {
+                continue;
+            }
+            dirty |= deduction.remove_possibility(&mut grid.cells[index], value)?;
+            deduction.add_pattern(&grid.cells, &[index], 1 << (value - 1));
+            deduction.detail = Some(trial.chain.join(" -> "));
+            if single_step {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(dirty)
+}
@@ -1,6 +1,10 @@
 use crate::cell::Cell;
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
 use crate::grid::Grid;
 use crate::solvers::Solver;
+use crate::MAX_SIZE;
+use itertools::Itertools;
 
 pub const HIDDEN_PAIR: Solver = Solver::new(
     "Hidden Pair",
@@ -9,51 +13,127 @@ pub const HIDDEN_PAIR: Solver = Solver::new(
     solve_hidden_pair,
     step_hidden_pair,
 );
-pub fn step_hidden_pair(grid: &mut Grid) -> bool {
-    for collection in &grid.unsolved_groups {
-        if solve_hidden_pair_collection_set(&mut grid.cells, collection) {
-            return true;
+pub const HIDDEN_TRIPLET: Solver = Solver::new(
+    "Hidden Triple",
+    "H3",
+    "Three values only showed up in the same 3 cells, removed all other possibilities from those cells",
+    solve_hidden_triple,
+    step_hidden_triple,
+);
+pub const HIDDEN_QUAD: Solver = Solver::new(
+    "Hidden Quad",
+    "H4",
+    "Four values only showed up in the same 4 cells, removed all other possibilities from those cells",
+    solve_hidden_quad,
+    step_hidden_quad,
+);
+pub fn step_hidden_pair(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    step_hidden_group(grid, &HIDDEN_PAIR, 2)
+}
+pub fn solve_hidden_pair(grid: &mut Grid) -> Result<bool, SudokuError> {
+    solve_hidden_group(grid, &HIDDEN_PAIR, 2)
+}
+pub fn step_hidden_triple(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    step_hidden_group(grid, &HIDDEN_TRIPLET, 3)
+}
+pub fn solve_hidden_triple(grid: &mut Grid) -> Result<bool, SudokuError> {
+    solve_hidden_group(grid, &HIDDEN_TRIPLET, 3)
+}
+pub fn step_hidden_quad(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    step_hidden_group(grid, &HIDDEN_QUAD, 4)
+}
+pub fn solve_hidden_quad(grid: &mut Grid) -> Result<bool, SudokuError> {
+    solve_hidden_group(grid, &HIDDEN_QUAD, 4)
+}
+fn step_hidden_group(
+    grid: &mut Grid,
+    technique: &Solver,
+    group_size: usize,
+) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(technique);
+    for collection in grid.unsolved_groups.iter() {
+        if solve_hidden_group_collection(
+            &mut grid.cells,
+            &mut deduction,
+            collection,
+            group_size,
+            true,
+        )? {
+            break;
         }
     }
-    false
+    Ok(deduction.finish())
 }
-pub fn solve_hidden_pair(grid: &mut Grid) -> bool {
+fn solve_hidden_group(
+    grid: &mut Grid,
+    technique: &Solver,
+    group_size: usize,
+) -> Result<bool, SudokuError> {
     let mut dirty = false;
-    for collection in &grid.unsolved_groups {
-        dirty |= solve_hidden_pair_collection_set(&mut grid.cells, collection);
+    let mut deduction = Deduction::new(technique);
+    for collection in grid.unsolved_groups.iter() {
+        dirty |= solve_hidden_group_collection(
+            &mut grid.cells,
+            &mut deduction,
+            collection,
+            group_size,
+            false,
+        )?;
     }
-    dirty
+    Ok(dirty)
 }
-fn solve_hidden_pair_collection_set(cells: &mut [Cell; 81], collection: &Vec<Vec<usize>>) -> bool {
+// Looks for `group_size` values that only show up in the same `group_size` cells of a group, those
+// cells have to hold those values, so every other possibility is removed from them
+fn solve_hidden_group_collection(
+    cells: &mut [Cell],
+    deduction: &mut Deduction,
+    collection: &[Vec<usize>],
+    group_size: usize,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
     let mut dirty = false;
-    'groups: for group in collection {
-        let mut counts: Vec<Vec<usize>> = vec![Vec::new(); 9];
-        for index in 0..group.len() {
-            let possibilities = cells[group[index]].get_possibilities();
-            for possibility in possibilities {
-                counts[possibility as usize - 1].push(index);
+    for group in collection {
+        // Bitset of the indices in the group each value could go in
+        let mut occurrences = [0u32; MAX_SIZE];
+        for (index, &cell_index) in group.iter().enumerate() {
+            for possibility in cells[cell_index].get_possibilities() {
+                occurrences[possibility as usize - 1] |= 1 << index;
             }
         }
-        for i in 0..group.len() - 1 {
-            if counts[i].len() != 2 {
+        let values = (0..group.len()).filter(|&value| {
+            occurrences[value] != 0 && occurrences[value].count_ones() as usize <= group_size
+        });
+        for combination in values.combinations(group_size) {
+            let mut found_indices = 0u32;
+            let mut found_candidates = 0u32;
+            for &value in combination.iter() {
+                found_indices |= occurrences[value];
+                found_candidates |= 1 << value;
+            }
+            if found_indices.count_ones() as usize != group_size {
                 continue;
             }
-            for j in i + 1..group.len() {
-                if counts[j].len() != 2 {
+            let mut removed = false;
+            for (index, &cell_index) in group.iter().enumerate() {
+                if found_indices & (1 << index) == 0 {
                     continue;
                 }
-                let new_candidates = (1 << i) | (1 << j);
-                if counts[i].eq(&counts[j]) {
-                    for &index in counts[i].iter() {
-                        if cells[group[index]].candidates != new_candidates {
-                            cells[group[index]].candidates = new_candidates;
-                            dirty = true;
-                        }
-                    }
-                    continue 'groups;
-                }
+                let cell = &mut cells[cell_index];
+                let others = cell.candidates & !found_candidates;
+                removed |= deduction.remove_possibilities(cell, others)?;
+            }
+            if removed {
+                let found_cells = (0..group.len())
+                    .filter(|&index| found_indices & (1 << index) != 0)
+                    .map(|index| group[index])
+                    .collect::<Vec<usize>>();
+                deduction.add_pattern(cells, &found_cells, found_candidates);
+            }
+            dirty |= removed;
+            if removed && single_step {
+                return Ok(true);
             }
         }
     }
-    dirty
+    Ok(dirty)
 }
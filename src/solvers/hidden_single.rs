@@ -1,7 +1,9 @@
 use crate::cell::Cell;
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
 use crate::grid::Grid;
 use crate::solvers::Solver;
-use crate::Position;
+use crate::{Position, MAX_SIZE};
 
 pub const HIDDEN_SINGLE: Solver = Solver::new(
     "Hidden Single",
@@ -11,44 +13,43 @@ pub const HIDDEN_SINGLE: Solver = Solver::new(
     step_hidden_single,
 );
 
-pub fn step_hidden_single(grid: &mut Grid) -> bool {
+pub fn step_hidden_single(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&HIDDEN_SINGLE);
     let mut result = None;
-    for group in &grid.unsolved_groups {
+    for group in grid.unsolved_groups.iter() {
         result = solve_hidden_single_collection(&mut grid.cells, group);
         if result.is_some() {
             break;
         }
     }
-    if result.is_some() {
-        let (pos, val) = result.unwrap();
-        grid.set_cell(pos, val);
-        return true;
+    if let Some((pos, val)) = result {
+        deduction.add_pattern(&grid.cells, &[grid.index(pos)], 1 << (val - 1));
+        deduction.set_cell(grid, pos, val)?;
     }
-    false
+    Ok(deduction.finish())
 }
 
-pub fn solve_hidden_single(grid: &mut Grid) -> bool {
+pub fn solve_hidden_single(grid: &mut Grid) -> Result<bool, SudokuError> {
     let mut dirty = false;
     let mut results = Vec::new();
-    for group in &grid.unsolved_groups {
+    for group in grid.unsolved_groups.iter() {
         let result = solve_hidden_single_collection(&mut grid.cells, group);
-        if result.is_some() {
+        if let Some(result) = result {
             dirty = true;
-            let result = result.unwrap();
             results.push(result);
         }
     }
     for result in results {
-        grid.set_cell(result.0, result.1);
+        grid.set_cell(result.0, result.1)?;
     }
-    dirty
+    Ok(dirty)
 }
 fn solve_hidden_single_collection(
-    cells: &mut [Cell; 81],
-    collection: &Vec<Vec<usize>>,
+    cells: &mut [Cell],
+    collection: &[Vec<usize>],
 ) -> Option<(Position, u8)> {
     for group in collection {
-        let mut count_and_positions = [(0, 0); 10];
+        let mut count_and_positions = [(0, 0); MAX_SIZE + 1];
         for i in 0..group.len() {
             let possibilities = cells[group[i]].get_possibilities();
             for &possibility in possibilities.iter() {
@@ -58,7 +59,7 @@ fn solve_hidden_single_collection(
         }
         for (i, (count, index)) in count_and_positions.iter().enumerate() {
             if *count == 1 {
-                return Some((Position::from_index(group[*index]), i as u8));
+                return Some((cells[group[*index]].position, i as u8));
             }
         }
     }
@@ -66,9 +67,9 @@ fn solve_hidden_single_collection(
 }
 #[allow(unused)]
 fn solve_hidden_single_cell(grid: &Grid, pos: Position) -> Option<u8> {
-    let cell_index = pos.get_index();
-    let possibilities = grid.cells[pos.get_index()].get_possibilities();
-    let groups = Grid::get_cell_groups(pos);
+    let cell_index = grid.index(pos);
+    let possibilities = grid.cells[cell_index].get_possibilities();
+    let groups = grid.get_cell_groups(pos);
     for group in groups {
         let mut candidate_clone = possibilities.clone();
         for cell in group {
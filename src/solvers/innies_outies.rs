@@ -0,0 +1,123 @@
+use crate::constraint::{Cage, Constraint};
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::Solver;
+use crate::GroupType;
+
+pub const INNIES_OUTIES: Solver = Solver::new(
+    "Innies and Outies",
+    "IO",
+    "A house adds up to every value, so taking away the cages inside it leaves what the rest of its cells (innies), or the cells of its cages that stick out of it (outies), add up to, which rules out values like a cage",
+    solve_innies_outies,
+    step_innies_outies,
+);
+pub fn step_innies_outies(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&INNIES_OUTIES);
+    innies_outies(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_innies_outies(grid: &mut Grid) -> Result<bool, SudokuError> {
+    innies_outies(grid, &mut Deduction::new(&INNIES_OUTIES), false)
+}
+fn innies_outies(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    if grid.cages().is_empty() {
+        return Ok(false);
+    }
+    let mut dirty = false;
+    let size = grid.size();
+    let house_sum = size * (size + 1) / 2;
+    let cages = grid.cages().to_vec();
+    let groups = grid.unsolved_groups.clone();
+    let group_types = [
+        (GroupType::Rows, "row"),
+        (GroupType::Columns, "column"),
+        (GroupType::Regions, "region"),
+        (GroupType::Extra, "extra house"),
+    ];
+    for (group_type, name) in group_types {
+        for (i, house) in groups[group_type as usize].iter().enumerate() {
+            let touching = cages
+                .iter()
+                .filter(|cage| cage.cells().iter().any(|index| house.contains(index)))
+                .collect::<Vec<&Cage>>();
+            let (inside, sticking_out): (Vec<&Cage>, Vec<&Cage>) = touching
+                .iter()
+                .partition(|cage| cage.cells().iter().all(|index| house.contains(index)));
+            let inside_sum = inside.iter().map(|cage| cage.sum()).sum::<usize>();
+            let innies = house
+                .iter()
+                .copied()
+                .filter(|index| !inside.iter().any(|cage| cage.cells().contains(index)))
+                .collect::<Vec<usize>>();
+            // Innies are all in the house, so they can't repeat a value, just like a cage
+            if !inside.is_empty() && !innies.is_empty() && inside_sum < house_sum {
+                let innie_cage = Cage::new(grid, innies, house_sum - inside_sum);
+                let detail = format!("Innies of {} {}", name, i + 1);
+                if restrict(grid, deduction, &innie_cage, detail)? {
+                    dirty = true;
+                    if single_step {
+                        return Ok(true);
+                    }
+                }
+            }
+            // Outies are only known to add up to the rest when every cell of the house is caged,
+            // and can only be treated like a cage if they all see each other
+            let caged = house
+                .iter()
+                .all(|index| touching.iter().any(|cage| cage.cells().contains(index)));
+            let outies = sticking_out
+                .iter()
+                .flat_map(|cage| cage.cells().iter().copied())
+                .filter(|index| !house.contains(index))
+                .collect::<Vec<usize>>();
+            let touching_sum = touching.iter().map(|cage| cage.sum()).sum::<usize>();
+            let distinct = outies
+                .iter()
+                .enumerate()
+                .all(|(n, &a)| outies[n + 1..].iter().all(|&b| grid.sees(a, b)));
+            if !caged || outies.is_empty() || !distinct || touching_sum <= house_sum {
+                continue;
+            }
+            let outie_cage = Cage::new(grid, outies, touching_sum - house_sum);
+            let detail = format!("Outies of {} {}", name, i + 1);
+            if restrict(grid, deduction, &outie_cage, detail)? {
+                dirty = true;
+                if single_step {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+    Ok(dirty)
+}
+// Removes the candidates of the cells that the cage rules out
+fn restrict(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    cage: &Cage,
+    detail: String,
+) -> Result<bool, SudokuError> {
+    let allowed = cage.restrict(grid);
+    let mut removed = 0;
+    for (&index, &allowed) in cage.cells().iter().zip(allowed.iter()) {
+        let cell = &mut grid.cells[index];
+        if cell.value != 0 {
+            continue;
+        }
+        let ruled_out = cell.candidates & !allowed;
+        if deduction.remove_possibilities(cell, ruled_out)? {
+            removed |= ruled_out;
+        }
+    }
+    if removed == 0 {
+        return Ok(false);
+    }
+    deduction.add_pattern(&grid.cells, cage.cells(), removed);
+    deduction.detail = Some(format!("{} add up to {}", detail, cage.sum()));
+    Ok(true)
+}
@@ -0,0 +1,53 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::GroupType;
+
+/// Two cells that are the only places a value can go in a group, so one of them has to be it
+pub(crate) struct ConjugatePair {
+    pub(crate) cells: [usize; 2],
+    pub(crate) group_type: GroupType,
+}
+/// Returns every conjugate pair of a value, a pair of cells can show up once for each group type
+/// they share
+pub(crate) fn conjugate_pairs(grid: &Grid, value: u8) -> Vec<ConjugatePair> {
+    let group_types = [
+        GroupType::Rows,
+        GroupType::Columns,
+        GroupType::Regions,
+        GroupType::Extra,
+    ];
+    let mut pairs = Vec::new();
+    for (collection, group_type) in grid.unsolved_groups.iter().zip(group_types) {
+        for group in collection {
+            let cells = group
+                .iter()
+                .copied()
+                .filter(|&index| grid.cells[index].contains_value(value))
+                .collect::<Vec<usize>>();
+            if cells.len() == 2 {
+                pairs.push(ConjugatePair {
+                    cells: [cells[0], cells[1]],
+                    group_type,
+                });
+            }
+        }
+    }
+    pairs
+}
+/// Removes the value from every cell that sees both of the given cells
+pub(crate) fn remove_seen_by_both(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    value: u8,
+    a: usize,
+    b: usize,
+) -> Result<bool, SudokuError> {
+    let mut removed = false;
+    for index in 0..grid.cells.len() {
+        if grid.sees(a, index) && grid.sees(b, index) {
+            removed |= deduction.remove_possibility(&mut grid.cells[index], value)?;
+        }
+    }
+    Ok(removed)
+}
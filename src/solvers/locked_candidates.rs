@@ -1,7 +1,8 @@
-use crate::cell::Cell;
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
 use crate::grid::Grid;
 use crate::solvers::Solver;
-use crate::{GroupType, Position, COLS, REGS, ROWS};
+use crate::GroupType;
 use std::collections::HashSet;
 
 pub const LOCKED_CANDIDATES: Solver = Solver::new(
@@ -11,124 +12,159 @@ pub const LOCKED_CANDIDATES: Solver = Solver::new(
     solve_locked_candidates,
     step_locked_candidates,
 );
-pub fn step_locked_candidates(grid: &mut Grid) -> bool {
-    let mut dirty = false;
-    //self.print_board();
-    //self.print_possibilities();
-    dirty |= solved_locked_candidates_line_region(&mut grid.cells, ROWS);
-    dirty |= solved_locked_candidates_line_region(&mut grid.cells, COLS);
-    for i in 0..9 {
-        dirty |= filter_region_by_lines(grid, i);
-    }
-    dirty
+pub fn step_locked_candidates(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&LOCKED_CANDIDATES);
+    locked_candidates(grid, &mut deduction)?;
+    Ok(deduction.finish())
+}
+pub fn solve_locked_candidates(grid: &mut Grid) -> Result<bool, SudokuError> {
+    locked_candidates(grid, &mut Deduction::new(&LOCKED_CANDIDATES))
 }
-pub fn solve_locked_candidates(grid: &mut Grid) -> bool {
+fn locked_candidates(grid: &mut Grid, deduction: &mut Deduction) -> Result<bool, SudokuError> {
     let mut dirty = false;
     //self.print_board();
     //self.print_possibilities();
-    dirty |= solved_locked_candidates_line_region(&mut grid.cells, ROWS);
-    dirty |= solved_locked_candidates_line_region(&mut grid.cells, COLS);
-    for i in 0..9 {
-        dirty |= filter_region_by_lines(grid, i);
+    dirty |= solved_locked_candidates_line_region(grid, deduction, GroupType::Rows)?;
+    dirty |= solved_locked_candidates_line_region(grid, deduction, GroupType::Columns)?;
+    for i in 0..grid.size() {
+        dirty |= filter_region_by_lines(grid, deduction, i)?;
     }
-    dirty
+    dirty |= filter_extra_houses(grid, deduction)?;
+    Ok(dirty)
 }
 fn solved_locked_candidates_line_region(
-    cells: &mut [Cell; 81],
-    line_collection: &[[usize; 9]; 9],
-) -> bool {
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    line_type: GroupType,
+) -> Result<bool, SudokuError> {
     let mut dirty = false;
-    for group in line_collection {
+    let lines = grid.unsolved_groups[line_type as usize].clone();
+    for group in lines.iter() {
         //gather each region
-        let mut region_values: Vec<u16> = Vec::new();
-        for i in 0..3 {
-            //gather within a region
-            let mut region_candidates = 0u16;
-            for j in 0..3 {
-                let cell = group[i * 3 + j];
-                region_candidates |= cells[cell].candidates;
-            }
-            region_values.push(region_candidates);
-        }
-        let ab_overlap = region_values[0] & region_values[1];
-        let ac_overlap = region_values[0] & region_values[2];
-        let bc_overlap = region_values[1] & region_values[2];
-        let a_unique = region_values[0] & !ab_overlap & !ac_overlap;
-        let b_unique = region_values[1] & !ab_overlap & !bc_overlap;
-        let c_unique = region_values[2] & !ac_overlap & !bc_overlap;
-        let a_region = Position::from_index(group[0]).region().0;
-        let b_region = Position::from_index(group[3]).region().0;
-        let c_region = Position::from_index(group[6]).region().0;
-        for i in REGS[a_region] {
-            if group.contains(&i) {
-                continue;
-            }
-            if cells[i].remove_possibilities(a_unique) {
-                dirty = true;
-                //println!("Removed!");
-            }
-        }
-        for i in REGS[b_region] {
-            if group.contains(&i) {
-                continue;
-            }
-            if cells[i].remove_possibilities(b_unique) {
-                dirty = true;
-                //println!("Removed!");
+        let intersections = line_intersections(grid, group);
+        let region_values = intersections
+            .iter()
+            .map(|(_, intersection)| {
+                intersection
+                    .iter()
+                    .fold(0u32, |acc, &cell| acc | grid.cells[cell].candidates)
+            })
+            .collect::<Vec<u32>>();
+        for (n, (region, intersection)) in intersections.iter().enumerate() {
+            // Values that only show up where the line crosses this region
+            let unique = (0..intersections.len())
+                .filter(|&other| other != n)
+                .fold(region_values[n], |acc, other| acc & !region_values[other]);
+            let region_cells = grid.unsolved_groups[GroupType::Regions as usize][*region].clone();
+            for i in region_cells {
+                if group.contains(&i) {
+                    continue;
+                }
+                if deduction.remove_possibilities(&mut grid.cells[i], unique)? {
+                    dirty = true;
+                    deduction.add_pattern(&grid.cells, intersection, unique);
+                    //println!("Removed!");
+                }
             }
         }
-        for i in REGS[c_region] {
-            if group.contains(&i) {
-                continue;
-            }
-            if cells[i].remove_possibilities(c_unique) {
-                dirty = true;
-                //println!("Removed!");
-            }
+    }
+    Ok(dirty)
+}
+/// Splits a line into the sets of cells where it crosses each region, along with the region
+pub(crate) fn line_intersections(grid: &Grid, line: &[usize]) -> Vec<(usize, Vec<usize>)> {
+    let mut intersections: Vec<(usize, Vec<usize>)> = Vec::new();
+    for &index in line {
+        let region = grid.region(index);
+        match intersections.iter_mut().find(|(other, _)| *other == region) {
+            Some((_, cells)) => cells.push(index),
+            None => intersections.push((region, vec![index])),
         }
     }
-    dirty
+    intersections
 }
-fn filter_region_by_lines(grid: &mut Grid, region_index: usize) -> bool {
+fn filter_region_by_lines(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    region_index: usize,
+) -> Result<bool, SudokuError> {
     let mut dirty = false;
-    for num in 1..=9 {
+    for num in 1..=grid.size() as u8 {
         let mut rows_found: HashSet<usize> = HashSet::new();
         let mut cols_found: HashSet<usize> = HashSet::new();
-        let region = REGS[region_index];
-        for index in region {
+        let region = grid.unsolved_groups[GroupType::Regions as usize][region_index].clone();
+        let mut removed = false;
+        for &index in region.iter() {
             if grid.cells[index].contains_value(num) {
-                let pos = Position::from_index(index);
+                let pos = grid.position(index);
                 rows_found.insert(pos.row);
                 cols_found.insert(pos.col);
             }
         }
+        let mut lines = Vec::new();
         if rows_found.len() == 1 {
             let row = rows_found.iter().last().unwrap();
-            for i in 0..9 {
-                let region_found = Position::new(*row, i).region().0;
-                if region_found != region_index {
-                    let cell =
-                        &mut grid.cells[grid.unsolved_groups[GroupType::Rows as usize][*row][i]];
-                    if cell.contains_value(num) {
-                        dirty = true;
-                        cell.remove_possibility(num);
-                    }
-                }
-            }
+            lines.push(grid.unsolved_groups[GroupType::Rows as usize][*row].clone());
         }
         if cols_found.len() == 1 {
             let col = cols_found.iter().last().unwrap();
-            for i in 0..9 {
-                let region_found = Position::new(i, *col).region().0;
-                if region_found != region_index {
-                    let cell = &mut grid.cells[COLS[*col][i]];
-                    if cell.contains_value(num) {
-                        dirty = true;
-                        cell.remove_possibility(num);
-                    }
+            lines.push(grid.unsolved_groups[GroupType::Columns as usize][*col].clone());
+        }
+        for index in lines.into_iter().flatten() {
+            if grid.region(index) != region_index {
+                removed |= deduction.remove_possibility(&mut grid.cells[index], num)?;
+            }
+        }
+        if removed {
+            let found = region
+                .iter()
+                .copied()
+                .filter(|&index| grid.cells[index].contains_value(num))
+                .collect::<Vec<usize>>();
+            deduction.add_pattern(&grid.cells, &found, 1 << (num - 1));
+            dirty = true;
+        }
+    }
+    Ok(dirty)
+}
+// Extra houses, like the diagonals of Sudoku-X, lock candidates against every group they cross
+fn filter_extra_houses(grid: &mut Grid, deduction: &mut Deduction) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    let groups = grid.unsolved_groups.clone();
+    for house in groups[GroupType::Extra as usize].iter() {
+        for other in groups.iter().flatten() {
+            if other == house {
+                continue;
+            }
+            let intersection = house
+                .iter()
+                .copied()
+                .filter(|index| other.contains(index))
+                .collect::<Vec<usize>>();
+            if intersection.is_empty() {
+                continue;
+            }
+            let inside = intersection
+                .iter()
+                .fold(0u32, |acc, &index| acc | grid.cells[index].candidates);
+            for (from, to) in [(house, other), (other, house)] {
+                // Values that only show up where the two groups cross
+                let locked = from
+                    .iter()
+                    .filter(|index| !intersection.contains(index))
+                    .fold(inside, |acc, &index| acc & !grid.cells[index].candidates);
+                if locked == 0 {
+                    continue;
+                }
+                let mut removed = false;
+                for &index in to.iter().filter(|index| !intersection.contains(index)) {
+                    removed |= deduction.remove_possibilities(&mut grid.cells[index], locked)?;
+                }
+                if removed {
+                    deduction.add_pattern(&grid.cells, &intersection, locked);
+                    dirty = true;
                 }
             }
         }
     }
-    dirty
+    Ok(dirty)
 }
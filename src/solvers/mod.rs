@@ -1,84 +1,160 @@
+mod als;
+mod als_xy_wing;
+mod als_xz;
+mod brute_force;
+mod chain;
+mod coloring;
+mod constraint;
+mod death_blossom;
+mod empty_rectangle;
+mod fish;
+mod forcing_chain;
 mod hidden_pair;
 mod hidden_single;
+mod innies_outies;
+mod links;
 mod locked_candidates;
 mod naked_pair;
 mod naked_single;
-mod x_wing;
+mod remote_pairs;
+mod skyscraper;
+mod sue_de_coq;
+mod two_string_kite;
+mod uniqueness;
+mod w_wing;
+mod xy_wing;
+mod xyz_wing;
 
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
 use crate::grid::Grid;
-use crate::solvers::hidden_pair::HIDDEN_PAIR;
-use crate::solvers::hidden_single::HIDDEN_SINGLE;
-use crate::solvers::locked_candidates::LOCKED_CANDIDATES;
-use crate::solvers::naked_pair::{NAKED_PAIR, NAKED_QUAD, NAKED_TRIPLET};
-use crate::solvers::naked_single::NAKED_SINGLE;
-use crate::solvers::x_wing::X_WING;
-use crate::{parse_yes_no, query_args_or_user, CommandArgs};
-use clearscreen::clear;
-use crossterm::{cursor, style, terminal, QueueableCommand};
-use std::io;
-use std::io::{stdin, Stdout, Write};
+pub use crate::solvers::als_xy_wing::ALS_XY_WING;
+pub use crate::solvers::als_xz::ALS_XZ;
+pub(crate) use crate::solvers::brute_force::BruteForceSearch;
+pub use crate::solvers::brute_force::{find_solution, BRUTE_FORCE};
+pub use crate::solvers::chain::{AIC, GROUPED_AIC, X_CYCLE};
+pub use crate::solvers::coloring::{MULTI_COLORING, SIMPLE_COLORING};
+pub use crate::solvers::constraint::CONSTRAINT;
+pub use crate::solvers::death_blossom::DEATH_BLOSSOM;
+pub use crate::solvers::empty_rectangle::EMPTY_RECTANGLE;
+pub use crate::solvers::fish::{
+    FINNED_JELLYFISH, FINNED_SWORDFISH, FINNED_X_WING, JELLYFISH, SASHIMI_JELLYFISH,
+    SASHIMI_SWORDFISH, SASHIMI_X_WING, SWORDFISH, X_WING,
+};
+pub use crate::solvers::forcing_chain::{CELL_FORCING_CHAIN, NISHIO, UNIT_FORCING_CHAIN};
+pub use crate::solvers::hidden_pair::{HIDDEN_PAIR, HIDDEN_QUAD, HIDDEN_TRIPLET};
+pub use crate::solvers::hidden_single::HIDDEN_SINGLE;
+pub use crate::solvers::innies_outies::INNIES_OUTIES;
+pub use crate::solvers::locked_candidates::LOCKED_CANDIDATES;
+pub use crate::solvers::naked_pair::{NAKED_PAIR, NAKED_QUAD, NAKED_TRIPLET};
+pub use crate::solvers::naked_single::NAKED_SINGLE;
+pub use crate::solvers::remote_pairs::REMOTE_PAIRS;
+pub use crate::solvers::skyscraper::SKYSCRAPER;
+pub use crate::solvers::sue_de_coq::SUE_DE_COQ;
+pub use crate::solvers::two_string_kite::TWO_STRING_KITE;
+pub use crate::solvers::uniqueness::{
+    BUG_PLUS_ONE, UNIQUE_RECTANGLE_1, UNIQUE_RECTANGLE_2, UNIQUE_RECTANGLE_3, UNIQUE_RECTANGLE_4,
+};
+pub use crate::solvers::w_wing::W_WING;
+pub use crate::solvers::xy_wing::XY_WING;
+pub use crate::solvers::xyz_wing::XYZ_WING;
 
-// TODO: Remaining Rules to add:
-//  XY-Wing
-//  XYZ-Wing
-//  Swordfish
-//  -
-//  Coloring
-//  Forcing Chain
-//  Nishio
-
-// Alternatively, add a final rule that will brute force the board
-pub const SOLVERS: [&Solver; 8] = [
+// BRUTE_FORCE can finish any valid board, but isn't a logical technique, so it is left out of
+// SOLVERS and has to be opted into as the last solver of a set
+pub const SOLVERS: [&Solver; 39] = [
     &NAKED_SINGLE,
     &HIDDEN_SINGLE,
+    &CONSTRAINT,
+    &INNIES_OUTIES,
     &NAKED_PAIR,
     &HIDDEN_PAIR,
     &NAKED_TRIPLET,
+    &HIDDEN_TRIPLET,
     &NAKED_QUAD,
+    &HIDDEN_QUAD,
     &LOCKED_CANDIDATES,
+    &SKYSCRAPER,
+    &TWO_STRING_KITE,
+    &EMPTY_RECTANGLE,
     &X_WING,
+    &FINNED_X_WING,
+    &SWORDFISH,
+    &SASHIMI_X_WING,
+    &SIMPLE_COLORING,
+    &REMOTE_PAIRS,
+    &XY_WING,
+    &W_WING,
+    &XYZ_WING,
+    &MULTI_COLORING,
+    &X_CYCLE,
+    &JELLYFISH,
+    &FINNED_SWORDFISH,
+    &SASHIMI_SWORDFISH,
+    &FINNED_JELLYFISH,
+    &SASHIMI_JELLYFISH,
+    &SUE_DE_COQ,
+    &ALS_XZ,
+    &ALS_XY_WING,
+    &DEATH_BLOSSOM,
+    &AIC,
+    &GROUPED_AIC,
+    &CELL_FORCING_CHAIN,
+    &UNIT_FORCING_CHAIN,
+    &NISHIO,
+];
+// These assume the board only has one solution, which isn't always true of a board that was typed
+// in, so they are left out of SOLVERS and have to be opted into. They also won't do anything until
+// the board has passed Grid::verify_uniqueness
+pub const UNIQUENESS_SOLVERS: [&Solver; 5] = [
+    &UNIQUE_RECTANGLE_1,
+    &UNIQUE_RECTANGLE_2,
+    &UNIQUE_RECTANGLE_3,
+    &UNIQUE_RECTANGLE_4,
+    &BUG_PLUS_ONE,
 ];
+/// Returns the solvers matching the given rule codes, in order from easiest to hardest, solvers in
+/// UNIQUENESS_SOLVERS are only included if their code is given. A rule code is a solver's
+/// abbreviation, e.g. N1 for Naked Single or LC for Locked Candidates
 pub fn get_solvers(filter: &str) -> Vec<&'static Solver> {
-    /* Rule Codes:
-     N1: Naked Single
-     H1: Hidden Single
-     N2: Naked Pair
-     H2: Hidden Pair
-     LC: Locked Candidates
-    */
-    if filter.len() == 0 {
+    if filter.is_empty() {
         return SOLVERS.to_vec();
     }
+    // Codes are read two characters at a time, so one code running into the next can't match a
+    // third code by accident, e.g. "SFXW" is Swordfish and X Wing, not Finned X Wing
+    let filter = filter
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect::<Vec<char>>();
+    let codes = filter
+        .chunks(2)
+        .map(|code| code.iter().collect::<String>())
+        .collect::<Vec<String>>();
     let mut solvers = Vec::new();
-    for solver in SOLVERS {
-        if filter.contains(solver.abbreviation) {
+    for solver in SOLVERS.into_iter().chain(UNIQUENESS_SOLVERS) {
+        if codes.iter().any(|code| code == solver.abbreviation) {
             solvers.push(solver);
         }
     }
     solvers
 }
-#[allow(unused)]
-pub fn solve_subset(grid: &mut Grid, solvers: &Vec<&Solver>) {
-    let mut dirty = true;
-    while dirty {
-        dirty = false;
-        for step in solvers {
-            let func = step.solve_function;
-            dirty |= func(grid);
-            if dirty {
-                break;
-            }
-        }
-    }
+/// Whether any of the solvers only work on a board that has been checked to have one solution
+pub fn needs_uniqueness(solvers: &[&Solver]) -> bool {
+    solvers.iter().any(|solver| {
+        UNIQUENESS_SOLVERS
+            .iter()
+            .any(|unique| unique.abbreviation == solver.abbreviation)
+    })
 }
-pub fn solve(grid: &mut Grid, _arguments: &CommandArgs) {
+/// Repeatedly applies the given solvers, always restarting from the first one after any progress,
+/// until none of them can make further progress
+pub fn solve_subset(grid: &mut Grid, solvers: &[&Solver]) -> Result<(), SudokuError> {
     let mut dirty = true;
     while dirty {
         dirty = false;
-
-        for step in SOLVERS {
+        for step in solvers {
             let func = step.solve_function;
-            dirty |= func(grid);
+            dirty |= func(grid)?;
             if dirty {
                 break;
             }
@@ -87,73 +163,39 @@ pub fn solve(grid: &mut Grid, _arguments: &CommandArgs) {
             break;
         }
     }
+    Ok(())
 }
-pub fn solve_async(grid: &mut Grid, arguments: &CommandArgs) {
-    let (_, should_auto_advance) = query_args_or_user(
-        "Auto Advance? Yes/No",
-        "Invalid input",
-        "-a",
-        arguments,
-        |x| parse_yes_no(x),
-    );
-
-    let mut stdout = io::stdout();
-    let mut dirty = true;
-    clear().expect("");
-    grid.auto_promote = false;
-    stdout.queue(cursor::DisableBlinking).unwrap();
-    while dirty {
-        dirty = false;
-        grid.clear_dirty();
-
-        for step in SOLVERS {
-            let func = step.step_function;
-            dirty |= func(grid);
-            if dirty {
-                print_and_flush_grid_changes(&mut stdout, grid, Some(step));
-                if should_auto_advance {
-                    std::thread::sleep(std::time::Duration::from_millis(1000));
-                } else {
-                    stdin()
-                        .read_line(&mut Default::default())
-                        .expect("Failed to read line");
-                }
-                break;
-            }
-        }
-        if grid.is_done() {
-            break;
-        }
-    }
+/// Solves as far as possible using every solver in [`SOLVERS`]
+pub fn solve(grid: &mut Grid) -> Result<(), SudokuError> {
+    solve_subset(grid, &SOLVERS)
 }
-pub fn print_and_flush_grid_changes(stdout: &mut Stdout, grid: &mut Grid, step: Option<&Solver>) {
-    print!("{}", cursor::MoveTo(0, 0));
-    let board = format!("{}\n", grid);
-    stdout.queue(style::Print(board)).unwrap();
-    stdout
-        .queue(terminal::Clear(terminal::ClearType::FromCursorDown))
-        .unwrap();
-    if step.is_some() {
-        let step = step.unwrap();
-        let step = format!("{}: {}", step.name, step.description);
-        stdout.queue(style::Print(step)).unwrap();
+/// Applies a single deduction from the first of the given solvers that can make progress,
+/// returning what it found and changed, or None if the board is stuck
+pub fn step(grid: &mut Grid, solvers: &[&Solver]) -> Result<Option<Deduction>, SudokuError> {
+    for solver in solvers {
+        if let Some(deduction) = solver.step(grid)? {
+            return Ok(Some(deduction));
+        }
     }
-    stdout.flush().unwrap();
+    Ok(None)
 }
+/// A single solving technique, with a function that applies it everywhere it can, and a function
+/// that applies it only once and returns the Deduction it made
+#[derive(Copy, Clone, Debug)]
 pub struct Solver {
     pub name: &'static str,
     pub description: &'static str,
     pub abbreviation: &'static str,
-    pub solve_function: fn(&mut Grid) -> bool,
-    pub step_function: fn(&mut Grid) -> bool,
+    pub solve_function: fn(&mut Grid) -> Result<bool, SudokuError>,
+    pub step_function: fn(&mut Grid) -> Result<Option<Deduction>, SudokuError>,
 }
 impl Solver {
-    const fn new(
+    pub const fn new(
         name: &'static str,
         abbreviation: &'static str,
         description: &'static str,
-        solve_function: fn(&mut Grid) -> bool,
-        step_function: fn(&mut Grid) -> bool,
+        solve_function: fn(&mut Grid) -> Result<bool, SudokuError>,
+        step_function: fn(&mut Grid) -> Result<Option<Deduction>, SudokuError>,
     ) -> Solver {
         Solver {
             name,
@@ -163,4 +205,9 @@ impl Solver {
             step_function,
         }
     }
+    /// Applies a single deduction with this solver, returning what it found and changed, or None if
+    /// it can't make progress
+    pub fn step(&self, grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+        (self.step_function)(grid)
+    }
 }
@@ -1,6 +1,9 @@
 use crate::cell::Cell;
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
 use crate::grid::Grid;
 use crate::solvers::Solver;
+use crate::MAX_SIZE;
 use itertools::Itertools;
 
 pub const NAKED_PAIR: Solver = Solver::new(
@@ -24,56 +27,66 @@ pub const NAKED_QUAD: Solver = Solver::new(
     solve_naked_quad,
     step_naked_quad,
 );
-pub fn step_naked_pair(grid: &mut Grid) -> bool {
-    for collection in &grid.unsolved_groups {
-        if solve_naked_pair_collection(&mut grid.cells, collection) {
-            return true;
+pub fn step_naked_pair(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&NAKED_PAIR);
+    for collection in grid.unsolved_groups.iter() {
+        if solve_naked_pair_collection(&mut grid.cells, &mut deduction, collection)? {
+            break;
         }
     }
-    false
+    Ok(deduction.finish())
 }
-pub fn solve_naked_pair(grid: &mut Grid) -> bool {
+pub fn solve_naked_pair(grid: &mut Grid) -> Result<bool, SudokuError> {
     let mut dirty = false;
-    for collection in &grid.unsolved_groups {
-        dirty |= solve_naked_pair_collection(&mut grid.cells, collection);
+    let mut deduction = Deduction::new(&NAKED_PAIR);
+    for collection in grid.unsolved_groups.iter() {
+        dirty |= solve_naked_pair_collection(&mut grid.cells, &mut deduction, collection)?;
     }
-    dirty
+    Ok(dirty)
 }
-pub fn step_naked_triple(grid: &mut Grid) -> bool {
-    for collection in &grid.unsolved_groups {
-        if step_naked_group_collection(&mut grid.cells, collection, 3) {
-            return true;
+pub fn step_naked_triple(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&NAKED_TRIPLET);
+    for collection in grid.unsolved_groups.iter() {
+        if step_naked_group_collection(&mut grid.cells, &mut deduction, collection, 3)? {
+            break;
         }
     }
-    false
+    Ok(deduction.finish())
 }
-pub fn solve_naked_triple(grid: &mut Grid) -> bool {
+pub fn solve_naked_triple(grid: &mut Grid) -> Result<bool, SudokuError> {
     let mut dirty = false;
-    for collection in &grid.unsolved_groups {
-        dirty |= solve_naked_group_collection(&mut grid.cells, collection, 3);
+    let mut deduction = Deduction::new(&NAKED_TRIPLET);
+    for collection in grid.unsolved_groups.iter() {
+        dirty |= solve_naked_group_collection(&mut grid.cells, &mut deduction, collection, 3)?;
     }
-    dirty
+    Ok(dirty)
 }
-pub fn step_naked_quad(grid: &mut Grid) -> bool {
-    for collection in &grid.unsolved_groups {
-        if step_naked_group_collection(&mut grid.cells, collection, 4) {
-            return true;
+pub fn step_naked_quad(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&NAKED_QUAD);
+    for collection in grid.unsolved_groups.iter() {
+        if step_naked_group_collection(&mut grid.cells, &mut deduction, collection, 4)? {
+            break;
         }
     }
-    false
+    Ok(deduction.finish())
 }
-pub fn solve_naked_quad(grid: &mut Grid) -> bool {
+pub fn solve_naked_quad(grid: &mut Grid) -> Result<bool, SudokuError> {
     let mut dirty = false;
-    for collection in &grid.unsolved_groups {
-        dirty |= solve_naked_group_collection(&mut grid.cells, collection, 4);
+    let mut deduction = Deduction::new(&NAKED_QUAD);
+    for collection in grid.unsolved_groups.iter() {
+        dirty |= solve_naked_group_collection(&mut grid.cells, &mut deduction, collection, 4)?;
     }
-    dirty
+    Ok(dirty)
 }
-fn solve_naked_pair_collection(cells: &mut [Cell; 81], collection: &Vec<Vec<usize>>) -> bool {
+fn solve_naked_pair_collection(
+    cells: &mut [Cell],
+    deduction: &mut Deduction,
+    collection: &[Vec<usize>],
+) -> Result<bool, SudokuError> {
     let mut dirty = false;
-    for i in 0..collection.len() {
-        let nine_cell = &collection[i];
-        let mut matched = 0u16;
+    for nine_cell in collection {
+        let mut matched = 0u32;
+        let mut pair = [0usize; 2];
         'search: for j in 0..nine_cell.len() - 1 {
             let cell_index = nine_cell[j];
             let cell = cells[cell_index];
@@ -82,32 +95,39 @@ fn solve_naked_pair_collection(cells: &mut [Cell; 81], collection: &Vec<Vec<usiz
                     let cell_2 = cells[nine_cell[k]];
                     if cell.candidates == cell_2.candidates {
                         matched = cell.candidates;
+                        pair = [cell_index, nine_cell[k]];
                         break 'search;
                     }
                 }
             }
         }
         if matched != 0 {
+            let mut removed = false;
             for j in 0..nine_cell.len() {
                 let cell = &mut cells[nine_cell[j]];
                 if cell.candidates != matched {
-                    dirty |= cell.remove_possibilities(matched);
+                    removed |= deduction.remove_possibilities(cell, matched)?;
                 }
             }
+            if removed {
+                deduction.add_pattern(cells, &pair, matched);
+            }
+            dirty |= removed;
         }
     }
-    dirty
+    Ok(dirty)
 }
 fn solve_naked_group_collection(
-    cells: &mut [Cell; 81],
-    collection: &Vec<Vec<usize>>,
+    cells: &mut [Cell],
+    deduction: &mut Deduction,
+    collection: &[Vec<usize>],
     group_size: usize,
-) -> bool {
+) -> Result<bool, SudokuError> {
     let mut dirty = false;
-    let combinations = (0..9).combinations(group_size);
     for i in 0..collection.len() {
-        let mut group_found: Option<(Vec<usize>, u16)> = None;
-        let mut candidates = [0; 9];
+        let combinations = (0..collection[i].len()).combinations(group_size);
+        let mut group_found: Option<(Vec<usize>, u32)> = None;
+        let mut candidates = [0; MAX_SIZE];
         for j in 0..collection[i].len() {
             candidates[j] = cells[collection[i][j]].candidates
         }
@@ -124,28 +144,38 @@ fn solve_naked_group_collection(
                 break 'combinatorics;
             }
         }
-        if group_found.is_some() {
-            let (cell_indices, candidates) = group_found.unwrap();
+        if let Some((cell_indices, candidates)) = group_found {
+            let mut removed = false;
             for j in 0..collection[i].len() {
                 if cell_indices.contains(&j) {
                     continue;
                 }
-                dirty |= cells[collection[i][j]].remove_possibilities(candidates);
+                removed |=
+                    deduction.remove_possibilities(&mut cells[collection[i][j]], candidates)?;
             }
+            if removed {
+                let group_cells = cell_indices
+                    .iter()
+                    .map(|&j| collection[i][j])
+                    .collect::<Vec<usize>>();
+                deduction.add_pattern(cells, &group_cells, candidates);
+            }
+            dirty |= removed;
         }
     }
-    dirty
+    Ok(dirty)
 }
 fn step_naked_group_collection(
-    cells: &mut [Cell; 81],
-    collection: &Vec<Vec<usize>>,
+    cells: &mut [Cell],
+    deduction: &mut Deduction,
+    collection: &[Vec<usize>],
     group_size: usize,
-) -> bool {
+) -> Result<bool, SudokuError> {
     let mut dirty = false;
-    let combinations = (0..9).combinations(group_size);
     for i in 0..collection.len() {
-        let mut group_found: Option<(Vec<usize>, u16)> = None;
-        let mut candidates = [0; 9];
+        let combinations = (0..collection[i].len()).combinations(group_size);
+        let mut group_found: Option<(Vec<usize>, u32)> = None;
+        let mut candidates = [0; MAX_SIZE];
         for j in 0..collection[i].len() {
             candidates[j] = cells[collection[i][j]].candidates
         }
@@ -162,18 +192,27 @@ fn step_naked_group_collection(
                 break 'combinatorics;
             }
         }
-        if group_found.is_some() {
-            let (cell_indices, candidates) = group_found.unwrap();
+        if let Some((cell_indices, candidates)) = group_found {
+            let mut removed = false;
             for j in 0..collection[i].len() {
                 if cell_indices.contains(&j) {
                     continue;
                 }
-                dirty |= cells[collection[i][j]].remove_possibilities(candidates);
+                removed |=
+                    deduction.remove_possibilities(&mut cells[collection[i][j]], candidates)?;
+            }
+            if removed {
+                let group_cells = cell_indices
+                    .iter()
+                    .map(|&j| collection[i][j])
+                    .collect::<Vec<usize>>();
+                deduction.add_pattern(cells, &group_cells, candidates);
             }
+            dirty |= removed;
             if dirty {
-                return true;
+                return Ok(true);
             }
         }
     }
-    dirty
+    Ok(dirty)
 }
@@ -1,3 +1,5 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
 use crate::grid::Grid;
 use crate::solvers::Solver;
 use crate::Position;
@@ -9,26 +11,26 @@ pub const NAKED_SINGLE: Solver = Solver::new(
     solve_naked_single,
     step_naked_single,
 );
-pub fn step_naked_single(grid: &mut Grid) -> bool {
+pub fn step_naked_single(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
     if grid.auto_promote {
-        return false;
+        return Ok(None);
     }
+    let mut deduction = Deduction::new(&NAKED_SINGLE);
     for index in 0..grid.cells.len() {
-        let cell = &mut grid.cells[index];
-        if cell.value > 0 {
+        let cell = &grid.cells[index];
+        if cell.value > 0 || cell.candidates.count_ones() != 1 {
             continue;
         }
-        let result = cell.promote_single_candidate();
-        if result {
-            grid.remove_seen_candidates(Position::from_index(index));
-            return true;
-        }
+        let value = cell.candidates.trailing_zeros() as u8 + 1;
+        deduction.add_pattern(&grid.cells, &[index], cell.candidates);
+        deduction.set_cell(grid, grid.position(index), value)?;
+        break;
     }
-    false
+    Ok(deduction.finish())
 }
-pub fn solve_naked_single(grid: &mut Grid) -> bool {
+pub fn solve_naked_single(grid: &mut Grid) -> Result<bool, SudokuError> {
     if grid.auto_promote {
-        return false;
+        return Ok(false);
     }
     let mut dirty = false;
     for index in 0..grid.cells.len() {
@@ -36,21 +38,17 @@ pub fn solve_naked_single(grid: &mut Grid) -> bool {
         if cell.value > 0 {
             continue;
         }
-        let result = cell.promote_single_candidate();
+        let result = cell.promote_single_candidate()?;
         if result {
-            grid.remove_seen_candidates(Position::from_index(index));
+            grid.remove_seen_candidates(grid.position(index))?;
             dirty = true;
         }
     }
-    dirty
+    Ok(dirty)
 }
 #[allow(unused)]
 fn solve_naked_single_cell(grid: &Grid, pos: Position) -> Option<u8> {
-    let cell = grid.get_cell(pos);
-    if cell.is_none() {
-        return None;
-    }
-    let cell = cell.unwrap();
+    let cell = grid.get_cell(pos)?;
     if cell.value != 0 {
         return Some(cell.value);
     }
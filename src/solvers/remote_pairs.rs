@@ -0,0 +1,100 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::Solver;
+use itertools::Itertools;
+use std::collections::VecDeque;
+
+pub const REMOTE_PAIRS: Solver = Solver::new(
+    "Remote Pairs",
+    "RP",
+    "A chain of cells with the same two values, each seeing the next, alternates between the two values, so cells that see both ends of an odd part of the chain can't be either value",
+    solve_remote_pairs,
+    step_remote_pairs,
+);
+pub fn step_remote_pairs(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&REMOTE_PAIRS);
+    remote_pairs(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_remote_pairs(grid: &mut Grid) -> Result<bool, SudokuError> {
+    remote_pairs(grid, &mut Deduction::new(&REMOTE_PAIRS), false)
+}
+fn remote_pairs(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    let bivalue: Vec<usize> = (0..grid.cells.len())
+        .filter(|&i| grid.cells[i].candidates.count_ones() == 2)
+        .collect();
+    let pairs = bivalue
+        .iter()
+        .map(|&index| grid.cells[index].candidates)
+        .unique()
+        .collect::<Vec<u32>>();
+    for pair in pairs {
+        let cells = bivalue
+            .iter()
+            .copied()
+            .filter(|&index| grid.cells[index].candidates == pair)
+            .collect::<Vec<usize>>();
+        if cells.len() < 4 {
+            continue;
+        }
+        for (i, &start) in cells.iter().enumerate() {
+            // How many steps along the chain each cell is from the start, and the cell before it
+            let mut distances: Vec<Option<usize>> = vec![None; cells.len()];
+            let mut parents = vec![i; cells.len()];
+            distances[i] = Some(0);
+            let mut queue = VecDeque::from([i]);
+            while let Some(current) = queue.pop_front() {
+                for next in 0..cells.len() {
+                    if distances[next].is_none() && grid.sees(cells[current], cells[next]) {
+                        distances[next] = Some(distances[current].unwrap() + 1);
+                        parents[next] = current;
+                        queue.push_back(next);
+                    }
+                }
+            }
+            for (j, &end) in cells.iter().enumerate() {
+                // An odd distance means the ends are opposite values, one apart is a naked pair
+                let Some(distance) = distances[j] else {
+                    continue;
+                };
+                if j < i || distance < 3 || distance % 2 == 0 {
+                    continue;
+                }
+                let mut removed = false;
+                for index in 0..grid.cells.len() {
+                    if grid.sees(start, index) && grid.sees(end, index) {
+                        let cell = &mut grid.cells[index];
+                        removed |= deduction.remove_possibilities(cell, pair)?;
+                    }
+                }
+                if !removed {
+                    continue;
+                }
+                dirty = true;
+                let mut chain = vec![end];
+                let mut current = j;
+                while current != i {
+                    current = parents[current];
+                    chain.push(cells[current]);
+                }
+                deduction.add_pattern(&grid.cells, &chain, pair);
+                let chain = chain
+                    .iter()
+                    .rev()
+                    .map(|&index| grid.position(index).to_string())
+                    .join(" - ");
+                deduction.detail = Some(format!("Chain {} alternates values", chain));
+                if single_step {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+    Ok(dirty)
+}
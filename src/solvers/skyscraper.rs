@@ -0,0 +1,77 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::links::{conjugate_pairs, remove_seen_by_both};
+use crate::solvers::Solver;
+use crate::GroupType;
+use itertools::Itertools;
+
+pub const SKYSCRAPER: Solver = Solver::new(
+    "Skyscraper",
+    "SK",
+    "A value only shows up twice in each of two lines, with one end of each lined up, one of the other two ends has to be the value, so it was removed from anything that sees both",
+    solve_skyscraper,
+    step_skyscraper,
+);
+pub fn step_skyscraper(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&SKYSCRAPER);
+    skyscraper(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_skyscraper(grid: &mut Grid) -> Result<bool, SudokuError> {
+    skyscraper(grid, &mut Deduction::new(&SKYSCRAPER), false)
+}
+fn skyscraper(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    for value in 1..=grid.size() as u8 {
+        let pairs = conjugate_pairs(grid, value);
+        for group_type in [GroupType::Rows, GroupType::Columns] {
+            // The index of a cell across the lines, so ends that line up have the same one
+            let size = grid.size();
+            let across = |index: usize| match group_type {
+                GroupType::Rows => index % size,
+                _ => index / size,
+            };
+            let lines = pairs
+                .iter()
+                .filter(|pair| pair.group_type == group_type)
+                .map(|pair| pair.cells)
+                .collect::<Vec<[usize; 2]>>();
+            for (line_a, line_b) in lines.iter().tuple_combinations() {
+                for (i, j) in (0..2).cartesian_product(0..2) {
+                    let (base_a, base_b) = (line_a[i], line_b[j]);
+                    let (top_a, top_b) = (line_a[1 - i], line_b[1 - j]);
+                    // If the tops lined up as well, this would be an X Wing
+                    if across(base_a) != across(base_b) || across(top_a) == across(top_b) {
+                        continue;
+                    }
+                    if !remove_seen_by_both(grid, deduction, value, top_a, top_b)? {
+                        continue;
+                    }
+                    dirty = true;
+                    deduction.add_pattern(
+                        &grid.cells,
+                        &[base_a, base_b, top_a, top_b],
+                        1 << (value - 1),
+                    );
+                    deduction.detail = Some(format!(
+                        "Value {} based at {} and {}, one of {} or {} has to be it",
+                        value,
+                        grid.position(base_a),
+                        grid.position(base_b),
+                        grid.position(top_a),
+                        grid.position(top_b)
+                    ));
+                    if single_step {
+                        return Ok(true);
+                    }
+                }
+            }
+        }
+    }
+    Ok(dirty)
+}
@@ -0,0 +1,147 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::locked_candidates::line_intersections;
+use crate::solvers::Solver;
+use crate::GroupType;
+use itertools::Itertools;
+
+pub const SUE_DE_COQ: Solver = Solver::new(
+    "Sue de Coq",
+    "SD",
+    "Cells where a line crosses a region, along with some cells from the rest of the line and some from the rest of the region, have as many values as cells, with no value shared between the line and region cells, so every value has a place, and was removed from the rest of the line or region it has to be in",
+    solve_sue_de_coq,
+    step_sue_de_coq,
+);
+pub fn step_sue_de_coq(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&SUE_DE_COQ);
+    sue_de_coq(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_sue_de_coq(grid: &mut Grid) -> Result<bool, SudokuError> {
+    sue_de_coq(grid, &mut Deduction::new(&SUE_DE_COQ), false)
+}
+// The most cells to take from the rest of the line or region
+const MAX_EXTRA_CELLS: usize = 3;
+// Only looks for line and region cells that have nothing but the intersection's values. Sets where
+// those cells bring in values of their own, which then have to be placed too, are left out, as
+// trying every cell of the line and region for them is far too slow on large boards
+fn sue_de_coq(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    let lines = [GroupType::Rows, GroupType::Columns]
+        .iter()
+        .flat_map(|&group_type| grid.unsolved_groups[group_type as usize].clone())
+        .collect::<Vec<Vec<usize>>>();
+    for line in lines.iter() {
+        for (region, intersection) in line_intersections(grid, line) {
+            let region = grid.unsolved_groups[GroupType::Regions as usize][region].clone();
+            let unsolved = |cells: &[usize]| {
+                cells
+                    .iter()
+                    .copied()
+                    .filter(|&index| grid.cells[index].value == 0)
+                    .filter(|index| !intersection.contains(index))
+                    .collect::<Vec<usize>>()
+            };
+            let (line_rest, region_rest) = (unsolved(line), unsolved(&region));
+            let centers = intersection
+                .iter()
+                .copied()
+                .filter(|&index| grid.cells[index].value == 0)
+                .collect::<Vec<usize>>();
+            for center in
+                (2..=centers.len()).flat_map(|size| centers.iter().copied().combinations(size))
+            {
+                let center_values = candidates(grid, &center);
+                let center_count = center_values.count_ones() as usize;
+                if center_count < center.len() + 2
+                    || center_count > center.len() + 2 * MAX_EXTRA_CELLS
+                {
+                    continue;
+                }
+                // Only cells with nothing but the intersection's values can help give each of its
+                // values a place, and every value needs one, so between them the line and region
+                // cells make up the difference
+                let fits = |cells: &[usize]| {
+                    cells
+                        .iter()
+                        .copied()
+                        .filter(|&index| grid.cells[index].candidates & !center_values == 0)
+                        .collect::<Vec<usize>>()
+                };
+                let (line_fits, region_fits) = (fits(&line_rest), fits(&region_rest));
+                let extra = center_count - center.len();
+                for line_size in 1..extra {
+                    let region_size = extra - line_size;
+                    if line_size > MAX_EXTRA_CELLS.min(line_fits.len())
+                        || region_size > MAX_EXTRA_CELLS.min(region_fits.len())
+                    {
+                        continue;
+                    }
+                    for line_cells in line_fits.iter().copied().combinations(line_size) {
+                        let line_values = candidates(grid, &line_cells);
+                        for region_cells in region_fits.iter().copied().combinations(region_size) {
+                            let region_values = candidates(grid, &region_cells);
+                            if line_values & region_values != 0 {
+                                continue;
+                            }
+                            // Every value now has one place, values that can't be in the
+                            // region cells have to be in the line, and the other way around
+                            let line_locked = line_values | (center_values & !region_values);
+                            let region_locked = region_values | (center_values & !line_values);
+                            let mut removed = false;
+                            for &index in line_rest.iter() {
+                                if !line_cells.contains(&index) {
+                                    removed |= deduction.remove_possibilities(
+                                        &mut grid.cells[index],
+                                        line_locked,
+                                    )?;
+                                }
+                            }
+                            for &index in region_rest.iter() {
+                                if !region_cells.contains(&index) {
+                                    removed |= deduction.remove_possibilities(
+                                        &mut grid.cells[index],
+                                        region_locked,
+                                    )?;
+                                }
+                            }
+                            if !removed {
+                                continue;
+                            }
+                            dirty = true;
+                            deduction.add_pattern(&grid.cells, &center, center_values);
+                            deduction.add_pattern(&grid.cells, &line_cells, line_values);
+                            deduction.add_pattern(&grid.cells, &region_cells, region_values);
+                            deduction.detail = Some(format!(
+                                "Intersection {} with {} in the line and {} in the region",
+                                format_cells(grid, &center),
+                                format_cells(grid, &line_cells),
+                                format_cells(grid, &region_cells)
+                            ));
+                            if single_step {
+                                return Ok(true);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(dirty)
+}
+fn candidates(grid: &Grid, cells: &[usize]) -> u32 {
+    cells
+        .iter()
+        .fold(0u32, |acc, &index| acc | grid.cells[index].candidates)
+}
+fn format_cells(grid: &Grid, cells: &[usize]) -> String {
+    cells
+        .iter()
+        .map(|&index| grid.position(index).to_string())
+        .join(", ")
+}
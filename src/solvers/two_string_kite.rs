@@ -0,0 +1,74 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::links::{conjugate_pairs, remove_seen_by_both};
+use crate::solvers::Solver;
+use crate::GroupType;
+use itertools::Itertools;
+
+pub const TWO_STRING_KITE: Solver = Solver::new(
+    "2-String Kite",
+    "KT",
+    "A value only shows up twice in a row and twice in a column, with one end of each in the same region, one of the other two ends has to be the value, so it was removed from anything that sees both",
+    solve_two_string_kite,
+    step_two_string_kite,
+);
+pub fn step_two_string_kite(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&TWO_STRING_KITE);
+    two_string_kite(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_two_string_kite(grid: &mut Grid) -> Result<bool, SudokuError> {
+    two_string_kite(grid, &mut Deduction::new(&TWO_STRING_KITE), false)
+}
+fn two_string_kite(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    for value in 1..=grid.size() as u8 {
+        let pairs = conjugate_pairs(grid, value);
+        let lines = |group_type: GroupType| {
+            pairs
+                .iter()
+                .filter(|pair| pair.group_type == group_type)
+                .map(|pair| pair.cells)
+                .collect::<Vec<[usize; 2]>>()
+        };
+        let (rows, cols) = (lines(GroupType::Rows), lines(GroupType::Columns));
+        for (row, col) in rows.iter().cartesian_product(cols.iter()) {
+            if row.iter().any(|cell| col.contains(cell)) {
+                continue;
+            }
+            for (i, j) in (0..2).cartesian_product(0..2) {
+                // The ends in the same region can't both be the value, so one of the others is
+                let (row_end, col_end) = (row[1 - i], col[1 - j]);
+                if grid.region(row[i]) != grid.region(col[j]) {
+                    continue;
+                }
+                if !remove_seen_by_both(grid, deduction, value, row_end, col_end)? {
+                    continue;
+                }
+                dirty = true;
+                deduction.add_pattern(
+                    &grid.cells,
+                    &[row[0], row[1], col[0], col[1]],
+                    1 << (value - 1),
+                );
+                deduction.detail = Some(format!(
+                    "Value {} joined at {} and {}, one of {} or {} has to be it",
+                    value,
+                    grid.position(row[i]),
+                    grid.position(col[j]),
+                    grid.position(row_end),
+                    grid.position(col_end)
+                ));
+                if single_step {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+    Ok(dirty)
+}
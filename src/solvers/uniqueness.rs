@@ -0,0 +1,355 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::Solver;
+use crate::GroupType;
+use itertools::Itertools;
+
+pub const UNIQUE_RECTANGLE_1: Solver = Solver::new(
+    "Unique Rectangle Type 1",
+    "U1",
+    "Three corners of a rectangle across two regions only have the same two values, if the fourth corner was also only those values the board would have two solutions, so they were removed from it",
+    solve_unique_rectangle_1,
+    step_unique_rectangle_1,
+);
+pub const UNIQUE_RECTANGLE_2: Solver = Solver::new(
+    "Unique Rectangle Type 2",
+    "U2",
+    "Two corners of a rectangle across two regions only have the same two values, and the other two have the same extra value, one of them has to be the extra value, so it was removed from anything that sees both",
+    solve_unique_rectangle_2,
+    step_unique_rectangle_2,
+);
+pub const UNIQUE_RECTANGLE_3: Solver = Solver::new(
+    "Unique Rectangle Type 3",
+    "U3",
+    "Two corners of a rectangle across two regions only have the same two values, one of the other two has to be an extra value, so together they formed a naked group with other cells in their group",
+    solve_unique_rectangle_3,
+    step_unique_rectangle_3,
+);
+pub const UNIQUE_RECTANGLE_4: Solver = Solver::new(
+    "Unique Rectangle Type 4",
+    "U4",
+    "Two corners of a rectangle across two regions only have the same two values, and one of those values can only be in the other two corners of their group, so the other value was removed from them",
+    solve_unique_rectangle_4,
+    step_unique_rectangle_4,
+);
+pub const BUG_PLUS_ONE: Solver = Solver::new(
+    "BUG+1",
+    "BG",
+    "Every unsolved cell but one has two values, if the last one did too the board would have several solutions, so it was set to the value that shows up three times in its groups",
+    solve_bug_plus_one,
+    step_bug_plus_one,
+);
+pub fn step_unique_rectangle_1(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&UNIQUE_RECTANGLE_1);
+    unique_rectangle(grid, &mut deduction, 1, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_unique_rectangle_1(grid: &mut Grid) -> Result<bool, SudokuError> {
+    unique_rectangle(grid, &mut Deduction::new(&UNIQUE_RECTANGLE_1), 1, false)
+}
+pub fn step_unique_rectangle_2(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&UNIQUE_RECTANGLE_2);
+    unique_rectangle(grid, &mut deduction, 2, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_unique_rectangle_2(grid: &mut Grid) -> Result<bool, SudokuError> {
+    unique_rectangle(grid, &mut Deduction::new(&UNIQUE_RECTANGLE_2), 2, false)
+}
+pub fn step_unique_rectangle_3(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&UNIQUE_RECTANGLE_3);
+    unique_rectangle(grid, &mut deduction, 3, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_unique_rectangle_3(grid: &mut Grid) -> Result<bool, SudokuError> {
+    unique_rectangle(grid, &mut Deduction::new(&UNIQUE_RECTANGLE_3), 3, false)
+}
+pub fn step_unique_rectangle_4(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&UNIQUE_RECTANGLE_4);
+    unique_rectangle(grid, &mut deduction, 4, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_unique_rectangle_4(grid: &mut Grid) -> Result<bool, SudokuError> {
+    unique_rectangle(grid, &mut Deduction::new(&UNIQUE_RECTANGLE_4), 4, false)
+}
+pub fn step_bug_plus_one(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&BUG_PLUS_ONE);
+    bug_plus_one(grid, &mut deduction)?;
+    Ok(deduction.finish())
+}
+pub fn solve_bug_plus_one(grid: &mut Grid) -> Result<bool, SudokuError> {
+    bug_plus_one(grid, &mut Deduction::new(&BUG_PLUS_ONE))
+}
+
+// Four unsolved cells at the corners of a rectangle spanning exactly two regions, that all still
+// have both values of the pair and aren't in any other house or constraint. If all four could only
+// be the pair, the two values could be swapped around the rectangle, giving a second solution
+struct Rectangle {
+    cells: [usize; 4],
+    pair: u32,
+}
+fn find_rectangles(grid: &Grid) -> Vec<Rectangle> {
+    let mut rectangles = Vec::new();
+    let size = grid.size();
+    for (row_1, row_2) in (0..size).tuple_combinations() {
+        for (col_1, col_2) in (0..size).tuple_combinations() {
+            let cells = [
+                row_1 * size + col_1,
+                row_1 * size + col_2,
+                row_2 * size + col_1,
+                row_2 * size + col_2,
+            ];
+            if cells
+                .iter()
+                .any(|&index| grid.cells[index].value != 0 || !is_swappable(grid, index))
+            {
+                continue;
+            }
+            let regions = cells
+                .iter()
+                .map(|&index| grid.region(index))
+                .unique()
+                .count();
+            if regions != 2 {
+                continue;
+            }
+            let common = cells.iter().fold(grid.all_candidates(), |acc, &index| {
+                acc & grid.cells[index].candidates
+            });
+            let values = (0..size).filter(|&bit| common & (1 << bit) != 0);
+            for (a, b) in values.tuple_combinations() {
+                rectangles.push(Rectangle {
+                    cells,
+                    pair: (1 << a) | (1 << b),
+                });
+            }
+        }
+    }
+    rectangles
+}
+// Whether the cell is only in its row, column and region. Swapping values around a rectangle keeps
+// those valid, but can break anything else the cell is in, like a diagonal or a killer cage
+fn is_swappable(grid: &Grid, index: usize) -> bool {
+    grid.layout.cell_constraints[index].is_empty()
+        && !grid.unsolved_groups[GroupType::Extra as usize]
+            .iter()
+            .any(|house| house.contains(&index))
+}
+fn unique_rectangle(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    kind: usize,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    if !grid.is_verified_unique() {
+        return Ok(false);
+    }
+    let mut dirty = false;
+    for rectangle in find_rectangles(grid) {
+        let pair = rectangle.pair;
+        // Earlier removals may have broken the rectangle up
+        if rectangle
+            .cells
+            .iter()
+            .any(|&index| grid.cells[index].candidates & pair != pair)
+        {
+            continue;
+        }
+        // The corners with more than just the pair
+        let roofs = rectangle
+            .cells
+            .iter()
+            .copied()
+            .filter(|&index| grid.cells[index].candidates != pair)
+            .collect::<Vec<usize>>();
+        let removed = match (kind, roofs.as_slice()) {
+            (1, &[roof]) => deduction.remove_possibilities(&mut grid.cells[roof], pair)?,
+            (2, &[roof_1, roof_2]) => type_2(grid, deduction, pair, roof_1, roof_2)?,
+            (3, &[roof_1, roof_2]) => type_3(grid, deduction, pair, roof_1, roof_2)?,
+            (4, &[roof_1, roof_2]) => type_4(grid, deduction, pair, roof_1, roof_2)?,
+            _ => false,
+        };
+        if !removed {
+            continue;
+        }
+        dirty = true;
+        deduction.add_pattern(&grid.cells, &rectangle.cells, pair);
+        let values = (1..=grid.size()).filter(|value| pair & (1 << (value - 1)) != 0);
+        deduction.detail = Some(format!(
+            "Rectangle {} on values {}",
+            rectangle
+                .cells
+                .iter()
+                .map(|&index| grid.position(index).to_string())
+                .join(", "),
+            values.map(|value| value.to_string()).join(" and ")
+        ));
+        if single_step {
+            return Ok(true);
+        }
+    }
+    Ok(dirty)
+}
+// Both roofs have the same single extra value, so one of them has to be it
+fn type_2(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    pair: u32,
+    roof_1: usize,
+    roof_2: usize,
+) -> Result<bool, SudokuError> {
+    let extra = grid.cells[roof_1].candidates & !pair;
+    if extra.count_ones() != 1 || grid.cells[roof_2].candidates & !pair != extra {
+        return Ok(false);
+    }
+    let value = extra.trailing_zeros() as u8 + 1;
+    let mut removed = false;
+    for index in 0..grid.cells.len() {
+        if index != roof_1
+            && index != roof_2
+            && grid.sees(roof_1, index)
+            && grid.sees(roof_2, index)
+        {
+            removed |= deduction.remove_possibility(&mut grid.cells[index], value)?;
+        }
+    }
+    Ok(removed)
+}
+// One of the roofs has to be one of their extra values, so together they act as a single cell
+// with only those values, which can form a naked group with other cells in a group they share
+fn type_3(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    pair: u32,
+    roof_1: usize,
+    roof_2: usize,
+) -> Result<bool, SudokuError> {
+    let extras = (grid.cells[roof_1].candidates | grid.cells[roof_2].candidates) & !pair;
+    let mut removed = false;
+    for group in shared_groups(grid, roof_1, roof_2) {
+        let others = group
+            .iter()
+            .copied()
+            .filter(|&index| index != roof_1 && index != roof_2)
+            .filter(|&index| grid.cells[index].value == 0)
+            .collect::<Vec<usize>>();
+        for size in 1..others.len() {
+            for combination in others.iter().combinations(size) {
+                let found = combination
+                    .iter()
+                    .fold(extras, |acc, &&index| acc | grid.cells[index].candidates);
+                if found.count_ones() as usize != size + 1 {
+                    continue;
+                }
+                for &index in others.iter() {
+                    if !combination.contains(&&index) {
+                        removed |= deduction.remove_possibilities(&mut grid.cells[index], found)?;
+                    }
+                }
+                if removed {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+    Ok(removed)
+}
+// If one of the pair can only go in the roofs within a group they share, the roofs can't both be
+// the other value of the pair without making the rectangle, so neither can be
+fn type_4(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    pair: u32,
+    roof_1: usize,
+    roof_2: usize,
+) -> Result<bool, SudokuError> {
+    let mut removed = false;
+    for group in shared_groups(grid, roof_1, roof_2) {
+        for bit in (0..grid.size() as u8).filter(|&bit| pair & (1 << bit) != 0) {
+            let value = bit + 1;
+            let only_roofs = group
+                .iter()
+                .filter(|&&index| grid.cells[index].contains_value(value))
+                .all(|&index| index == roof_1 || index == roof_2);
+            if !only_roofs {
+                continue;
+            }
+            let other = pair & !(1 << bit);
+            removed |= deduction.remove_possibilities(&mut grid.cells[roof_1], other)?;
+            removed |= deduction.remove_possibilities(&mut grid.cells[roof_2], other)?;
+        }
+    }
+    Ok(removed)
+}
+fn shared_groups(grid: &Grid, a: usize, b: usize) -> Vec<Vec<usize>> {
+    grid.unsolved_groups
+        .iter()
+        .flatten()
+        .filter(|group| group.contains(&a) && group.contains(&b))
+        .cloned()
+        .collect()
+}
+fn bug_plus_one(grid: &mut Grid, deduction: &mut Deduction) -> Result<bool, SudokuError> {
+    // The pattern left without the extra value only has two solutions if the rows, columns and
+    // regions are all there is to the board
+    let has_variants = !grid.unsolved_groups[GroupType::Extra as usize].is_empty()
+        || !grid.constraints().is_empty();
+    if !grid.is_verified_unique() || has_variants {
+        return Ok(false);
+    }
+    let mut extra_cell = None;
+    for index in 0..grid.cells.len() {
+        let cell = &grid.cells[index];
+        if cell.value != 0 {
+            continue;
+        }
+        match cell.candidates.count_ones() {
+            2 => {}
+            3 if extra_cell.is_none() => extra_cell = Some(index),
+            _ => return Ok(false),
+        }
+    }
+    let Some(index) = extra_cell else {
+        return Ok(false);
+    };
+    let position = grid.position(index);
+    for value in grid.cells[index].get_possibilities() {
+        let value = value as u8;
+        // Every other value shows up twice in each group, the extra one shows up three times
+        let is_extra = grid.get_cell_groups(position).iter().all(|group| {
+            group
+                .iter()
+                .filter(|&&other| grid.cells[other].contains_value(value))
+                .count()
+                == 3
+        });
+        if !is_extra || !is_bug(grid, index, value) {
+            continue;
+        }
+        let cell = &mut grid.cells[index];
+        let others = cell.candidates & !(1 << (value - 1));
+        deduction.remove_possibilities(cell, others)?;
+        deduction.add_pattern(&grid.cells, &[index], 1 << (value - 1));
+        deduction.detail = Some(format!("{} has to be {}", position, value));
+        return Ok(true);
+    }
+    Ok(false)
+}
+// Whether every value shows up exactly twice in each group it is left in, apart from the extra
+// value in the groups of the cell with three values. Without that, taking the extra value away
+// doesn't leave a pattern with two solutions, so nothing can be said about the cell
+fn is_bug(grid: &Grid, extra_cell: usize, extra_value: u8) -> bool {
+    grid.unsolved_groups.iter().flatten().all(|group| {
+        (1..=grid.size() as u8).all(|value| {
+            let count = group
+                .iter()
+                .filter(|&&index| grid.cells[index].contains_value(value))
+                .count();
+            let expected = match value == extra_value && group.contains(&extra_cell) {
+                true => 3,
+                false => 2,
+            };
+            count == 0 || count == expected
+        })
+    })
+}
@@ -0,0 +1,71 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::links::{conjugate_pairs, remove_seen_by_both};
+use crate::solvers::Solver;
+use itertools::Itertools;
+
+pub const W_WING: Solver = Solver::new(
+    "W Wing",
+    "WW",
+    "Two cells have the same two values XY, and X only shows up twice in a group, with each place seeing one of the cells, so one of the cells has to be Y, and Y was removed from every cell that sees both of them",
+    solve_w_wing,
+    step_w_wing,
+);
+pub fn step_w_wing(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&W_WING);
+    w_wing(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_w_wing(grid: &mut Grid) -> Result<bool, SudokuError> {
+    w_wing(grid, &mut Deduction::new(&W_WING), false)
+}
+fn w_wing(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    let bivalue: Vec<usize> = (0..grid.cells.len())
+        .filter(|&i| grid.cells[i].candidates.count_ones() == 2)
+        .collect();
+    for (&cell_a, &cell_b) in bivalue.iter().tuple_combinations() {
+        let candidates = grid.cells[cell_a].candidates;
+        // Cells that see each other would be a naked pair instead
+        if grid.cells[cell_b].candidates != candidates || grid.sees(cell_a, cell_b) {
+            continue;
+        }
+        for bit in (0..grid.size() as u8).filter(|&bit| candidates & (1 << bit) != 0) {
+            let (link_value, other_value) = (
+                bit + 1,
+                (candidates & !(1 << bit)).trailing_zeros() as u8 + 1,
+            );
+            for pair in conjugate_pairs(grid, link_value) {
+                let [end_a, end_b] = pair.cells;
+                if [end_a, end_b].contains(&cell_a) || [end_a, end_b].contains(&cell_b) {
+                    continue;
+                }
+                let connects = (grid.sees(end_a, cell_a) && grid.sees(end_b, cell_b))
+                    || (grid.sees(end_a, cell_b) && grid.sees(end_b, cell_a));
+                if !connects || !remove_seen_by_both(grid, deduction, other_value, cell_a, cell_b)?
+                {
+                    continue;
+                }
+                dirty = true;
+                deduction.add_pattern(&grid.cells, &[cell_a, cell_b, end_a, end_b], candidates);
+                deduction.detail = Some(format!(
+                    "{} and {} linked by {} through {} and {}",
+                    grid.position(cell_a),
+                    grid.position(cell_b),
+                    link_value,
+                    grid.position(end_a),
+                    grid.position(end_b)
+                ));
+                if single_step {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+    Ok(dirty)
+}
@@ -0,0 +1,74 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::Solver;
+
+pub const XY_WING: Solver = Solver::new(
+    "XY Wing",
+    "XY",
+    "A cell with two values XY sees two cells with XZ and YZ, whichever value the first cell is, one of the others must be Z, so Z was removed from every cell that sees both of them",
+    solve_xy_wing,
+    step_xy_wing,
+);
+pub fn step_xy_wing(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&XY_WING);
+    xy_wing(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_xy_wing(grid: &mut Grid) -> Result<bool, SudokuError> {
+    xy_wing(grid, &mut Deduction::new(&XY_WING), false)
+}
+fn xy_wing(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    let bivalue: Vec<usize> = (0..grid.cells.len())
+        .filter(|&i| grid.cells[i].candidates.count_ones() == 2)
+        .collect();
+    for &pivot in bivalue.iter() {
+        let pivot_candidates = grid.cells[pivot].candidates;
+        let pincers: Vec<usize> = bivalue
+            .iter()
+            .copied()
+            .filter(|&i| grid.sees(pivot, i))
+            // Each pincer has to share exactly one value with the pivot
+            .filter(|&i| (grid.cells[i].candidates & pivot_candidates).count_ones() == 1)
+            .collect();
+        for (a, &pincer_a) in pincers.iter().enumerate() {
+            for &pincer_b in pincers.iter().skip(a + 1) {
+                let candidates_a = grid.cells[pincer_a].candidates;
+                let candidates_b = grid.cells[pincer_b].candidates;
+                // The pincers have to share the value Z the pivot doesn't have, and not the
+                // value they each share with the pivot
+                let z = candidates_a & candidates_b;
+                if z.count_ones() != 1 || z & pivot_candidates != 0 {
+                    continue;
+                }
+                let z_value = z.trailing_zeros() as u8 + 1;
+                let mut removed = false;
+                for index in 0..grid.cells.len() {
+                    if index == pivot || !grid.sees(pincer_a, index) || !grid.sees(pincer_b, index)
+                    {
+                        continue;
+                    }
+                    removed |= deduction.remove_possibility(&mut grid.cells[index], z_value)?;
+                }
+                if !removed {
+                    continue;
+                }
+                dirty = true;
+                deduction.add_pattern(
+                    &grid.cells,
+                    &[pivot, pincer_a, pincer_b],
+                    pivot_candidates | candidates_a | candidates_b,
+                );
+                if single_step {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+    Ok(dirty)
+}
@@ -0,0 +1,69 @@
+use crate::deduction::Deduction;
+use crate::error::SudokuError;
+use crate::grid::Grid;
+use crate::solvers::Solver;
+
+pub const XYZ_WING: Solver = Solver::new(
+    "XYZ Wing",
+    "XZ",
+    "A cell with three values XYZ sees two cells with XZ and YZ, one of the three cells must be Z, so Z was removed from every cell that sees all three of them",
+    solve_xyz_wing,
+    step_xyz_wing,
+);
+pub fn step_xyz_wing(grid: &mut Grid) -> Result<Option<Deduction>, SudokuError> {
+    let mut deduction = Deduction::new(&XYZ_WING);
+    xyz_wing(grid, &mut deduction, true)?;
+    Ok(deduction.finish())
+}
+pub fn solve_xyz_wing(grid: &mut Grid) -> Result<bool, SudokuError> {
+    xyz_wing(grid, &mut Deduction::new(&XYZ_WING), false)
+}
+fn xyz_wing(
+    grid: &mut Grid,
+    deduction: &mut Deduction,
+    single_step: bool,
+) -> Result<bool, SudokuError> {
+    let mut dirty = false;
+    for pivot in 0..grid.cells.len() {
+        let pivot_candidates = grid.cells[pivot].candidates;
+        if pivot_candidates.count_ones() != 3 {
+            continue;
+        }
+        // Pincers are two value cells that only contain values from the pivot
+        let pincers: Vec<usize> = (0..grid.cells.len())
+            .filter(|&i| grid.sees(pivot, i))
+            .filter(|&i| grid.cells[i].candidates.count_ones() == 2)
+            .filter(|&i| grid.cells[i].candidates & !pivot_candidates == 0)
+            .collect();
+        for (a, &pincer_a) in pincers.iter().enumerate() {
+            for &pincer_b in pincers.iter().skip(a + 1) {
+                let candidates_a = grid.cells[pincer_a].candidates;
+                let candidates_b = grid.cells[pincer_b].candidates;
+                if candidates_a == candidates_b {
+                    continue;
+                }
+                let z = candidates_a & candidates_b;
+                let z_value = z.trailing_zeros() as u8 + 1;
+                let mut removed = false;
+                for index in 0..grid.cells.len() {
+                    if !grid.sees(pivot, index)
+                        || !grid.sees(pincer_a, index)
+                        || !grid.sees(pincer_b, index)
+                    {
+                        continue;
+                    }
+                    removed |= deduction.remove_possibility(&mut grid.cells[index], z_value)?;
+                }
+                if !removed {
+                    continue;
+                }
+                dirty = true;
+                deduction.add_pattern(&grid.cells, &[pivot, pincer_a, pincer_b], pivot_candidates);
+                if single_step {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+    Ok(dirty)
+}
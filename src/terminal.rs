@@ -0,0 +1,169 @@
+use crate::cli::{parse_yes_no, query_args_or_user, CommandArgs};
+use clearscreen::clear;
+use colored::Colorize;
+use crossterm::{cursor, style, terminal, QueueableCommand};
+use sodoku_solver::grid::Solution;
+use sodoku_solver::solvers::step;
+use sodoku_solver::{value_to_char, Deduction, Grid, GroupType, Solver, SudokuError};
+use std::io;
+use std::io::{stdin, Stdout, Write};
+
+pub fn solve_async(
+    grid: &mut Grid,
+    solvers: &[&Solver],
+    arguments: &CommandArgs,
+) -> Result<(), SudokuError> {
+    let (_, should_auto_advance) = query_args_or_user(
+        "Auto Advance? Yes/No",
+        "Invalid input",
+        "-a",
+        arguments,
+        parse_yes_no,
+    );
+
+    let mut stdout = io::stdout();
+    clear().expect("");
+    grid.auto_promote = false;
+    stdout.queue(cursor::DisableBlinking).unwrap();
+    loop {
+        grid.clear_dirty();
+
+        let Some(deduction) = step(grid, solvers)? else {
+            break;
+        };
+        print_and_flush_grid_changes(&mut stdout, grid, Some(&deduction));
+        if should_auto_advance {
+            std::thread::sleep(std::time::Duration::from_millis(1000));
+        } else {
+            stdin()
+                .read_line(&mut Default::default())
+                .expect("Failed to read line");
+        }
+        if grid.is_done() {
+            break;
+        }
+    }
+    Ok(())
+}
+// Shows hints for the next step a piece at a time, first the technique, then where to look, then
+// what it changes, and only moves the board on once the whole step has been shown
+pub fn give_hints(grid: &mut Grid, solvers: &[&Solver]) -> Result<(), SudokuError> {
+    let mut stdout = io::stdout();
+    clear().expect("");
+    grid.auto_promote = false;
+    stdout.queue(cursor::DisableBlinking).unwrap();
+    loop {
+        // Step a copy, so the board shown doesn't give the step away
+        let mut next = grid.clone();
+        next.clear_dirty();
+        let Some(deduction) = step(&mut next, solvers)? else {
+            break;
+        };
+        let hints = [
+            format!("Next technique: {}", deduction.technique.name),
+            format!("Look at {}", describe_location(grid, &deduction)),
+            deduction.to_string(),
+        ];
+        for shown in 1..=hints.len() {
+            let mut text = hints[..shown].join("\n");
+            if shown < hints.len() {
+                text += "\nPress enter for more";
+            } else {
+                text += "\nPress enter to apply it";
+            }
+            print_and_flush_text(&mut stdout, grid, Some(&text));
+            stdin()
+                .read_line(&mut Default::default())
+                .expect("Failed to read line");
+        }
+        *grid = next;
+        if grid.is_done() {
+            break;
+        }
+    }
+    Ok(())
+}
+// The houses and digits a deduction is about, falling back to its cells if no house has them all
+pub fn describe_location(grid: &Grid, deduction: &Deduction) -> String {
+    // A single cell is in all three of its houses, naming them all would give the cell away
+    let single_cell = deduction.pattern_cells.len() == 1;
+    let houses = deduction
+        .pattern_houses(grid)
+        .iter()
+        .filter(|&&(group_type, _)| !single_cell || group_type == GroupType::Regions)
+        .map(|&(group_type, i)| match group_type {
+            GroupType::Rows => format!("row {}", i + 1),
+            GroupType::Columns => format!("column {}", i + 1),
+            GroupType::Regions => format!("region {}", i + 1),
+            GroupType::Extra => format!("extra house {}", i + 1),
+        })
+        .collect::<Vec<String>>();
+    let mut location = if !houses.is_empty() {
+        houses.join(" and ")
+    } else if !deduction.pattern_cells.is_empty() {
+        deduction
+            .pattern_cells
+            .iter()
+            .map(|position| position.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    } else {
+        "the whole board".to_string()
+    };
+    match deduction.pattern_values.as_slice() {
+        [] => {}
+        [value] => location += &format!(", digit {}", value),
+        values => {
+            let values = values
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<String>>();
+            location += &format!(", digits {}", values.join(", "));
+        }
+    }
+    location
+}
+pub fn print_and_flush_grid_changes(
+    stdout: &mut Stdout,
+    grid: &mut Grid,
+    deduction: Option<&Deduction>,
+) {
+    let text = deduction.map(|deduction| deduction.to_string());
+    print_and_flush_text(stdout, grid, text.as_deref());
+}
+fn print_and_flush_text(stdout: &mut Stdout, grid: &Grid, text: Option<&str>) {
+    print!("{}", cursor::MoveTo(0, 0));
+    let board = format!("{}\n", grid);
+    stdout.queue(style::Print(board)).unwrap();
+    stdout
+        .queue(terminal::Clear(terminal::ClearType::FromCursorDown))
+        .unwrap();
+    if let Some(text) = text {
+        stdout.queue(style::Print(text)).unwrap();
+    }
+    stdout.flush().unwrap();
+}
+// Prints solutions side by side, highlighting any cells where they differ from each other, with a
+// bar between each region's columns
+pub fn print_solutions(solutions: &[Solution], box_cols: usize) {
+    let size = solutions.first().map_or(0, Vec::len);
+    for row in 0..size {
+        let mut line = String::new();
+        for solution in solutions {
+            for col in 0..size {
+                let value = solution[row][col];
+                let digit = value_to_char(value).to_string();
+                if solutions.iter().any(|other| other[row][col] != value) {
+                    line += &digit.blue().to_string();
+                } else {
+                    line += &digit;
+                }
+                if col % box_cols == box_cols - 1 && col != size - 1 {
+                    line += "|";
+                }
+            }
+            line += "    ";
+        }
+        println!("{}", line.trim_end());
+    }
+}
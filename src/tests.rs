@@ -1,11 +1,18 @@
+use sodoku_solver::grid::Solution;
+
 pub struct Test {
     pub(crate) board: &'static str,
-    pub(crate) answer: &'static [[u8; 9]; 9],
+    pub(crate) answer: &'static [&'static [u8]],
+}
+impl Test {
+    pub(crate) fn solution(&self) -> Solution {
+        self.answer.iter().map(|row| row.to_vec()).collect()
+    }
 }
 #[allow(dead_code)]
 pub mod all_tests {
     use super::*;
-    pub const ALL_TESTS: [Test; 15] = [
+    pub const ALL_TESTS: [Test; 56] = [
         easy_tests::TEST_1,
         easy_tests::TEST_2,
         easy_tests::TEST_3,
@@ -21,8 +28,49 @@ pub mod all_tests {
         hard_tests::TEST_6,
         hard_tests::TEST_7,
         rule_tests::HIDDEN_PAIR,
+        rule_tests::XY_WING,
+        rule_tests::XYZ_WING,
+        rule_tests::SWORDFISH,
+        rule_tests::JELLYFISH,
+        rule_tests::FINNED_X_WING,
+        rule_tests::SASHIMI_X_WING,
+        rule_tests::FINNED_SWORDFISH,
+        rule_tests::SASHIMI_SWORDFISH,
+        rule_tests::FINNED_JELLYFISH,
+        rule_tests::SASHIMI_JELLYFISH,
+        rule_tests::HIDDEN_TRIPLE,
+        rule_tests::HIDDEN_QUAD,
+        rule_tests::SIMPLE_COLORING,
+        rule_tests::MULTI_COLORING,
+        rule_tests::CELL_FORCING_CHAIN,
+        rule_tests::UNIT_FORCING_CHAIN,
+        rule_tests::NISHIO,
+        rule_tests::X_CYCLE,
+        rule_tests::AIC,
+        rule_tests::GROUPED_AIC,
+        rule_tests::SKYSCRAPER,
+        rule_tests::TWO_STRING_KITE,
+        rule_tests::EMPTY_RECTANGLE,
+        rule_tests::W_WING,
+        rule_tests::REMOTE_PAIRS,
+        rule_tests::ALS_XZ,
+        rule_tests::ALS_XY_WING,
+        rule_tests::DEATH_BLOSSOM,
+        rule_tests::SUE_DE_COQ,
+        rule_tests::UNIQUE_RECTANGLE_1,
+        rule_tests::UNIQUE_RECTANGLE_2,
+        rule_tests::UNIQUE_RECTANGLE_3,
+        rule_tests::UNIQUE_RECTANGLE_4,
+        rule_tests::BUG_PLUS_ONE,
+        size_tests::TEST_4X4,
+        size_tests::TEST_6X6,
+        size_tests::TEST_12X12,
+        size_tests::TEST_16X16,
+        variant_tests::SUDOKU_X,
+        variant_tests::JIGSAW,
+        variant_tests::KILLER,
     ];
-    pub const ALL_SOLVED_TESTS: [Test; 14] = [
+    pub const ALL_SOLVED_TESTS: [Test; 56] = [
         easy_tests::TEST_1,
         easy_tests::TEST_2,
         easy_tests::TEST_3,
@@ -37,8 +85,92 @@ pub mod all_tests {
         hard_tests::TEST_5,
         hard_tests::TEST_6,
         hard_tests::TEST_7,
+        rule_tests::HIDDEN_PAIR,
+        rule_tests::XY_WING,
+        rule_tests::XYZ_WING,
+        rule_tests::SWORDFISH,
+        rule_tests::JELLYFISH,
+        rule_tests::FINNED_X_WING,
+        rule_tests::SASHIMI_X_WING,
+        rule_tests::FINNED_SWORDFISH,
+        rule_tests::SASHIMI_SWORDFISH,
+        rule_tests::FINNED_JELLYFISH,
+        rule_tests::SASHIMI_JELLYFISH,
+        rule_tests::HIDDEN_TRIPLE,
+        rule_tests::HIDDEN_QUAD,
+        rule_tests::SIMPLE_COLORING,
+        rule_tests::MULTI_COLORING,
+        rule_tests::CELL_FORCING_CHAIN,
+        rule_tests::UNIT_FORCING_CHAIN,
+        rule_tests::NISHIO,
+        rule_tests::X_CYCLE,
+        rule_tests::AIC,
+        rule_tests::GROUPED_AIC,
+        rule_tests::SKYSCRAPER,
+        rule_tests::TWO_STRING_KITE,
+        rule_tests::EMPTY_RECTANGLE,
+        rule_tests::W_WING,
+        rule_tests::REMOTE_PAIRS,
+        rule_tests::ALS_XZ,
+        rule_tests::ALS_XY_WING,
+        rule_tests::DEATH_BLOSSOM,
+        rule_tests::SUE_DE_COQ,
+        rule_tests::UNIQUE_RECTANGLE_1,
+        rule_tests::UNIQUE_RECTANGLE_2,
+        rule_tests::UNIQUE_RECTANGLE_3,
+        rule_tests::UNIQUE_RECTANGLE_4,
+        rule_tests::BUG_PLUS_ONE,
+        size_tests::TEST_4X4,
+        size_tests::TEST_6X6,
+        size_tests::TEST_12X12,
+        size_tests::TEST_16X16,
+        variant_tests::SUDOKU_X,
+        variant_tests::JIGSAW,
+        variant_tests::KILLER,
+    ];
+    pub const ALL_UNSOLVED_TESTS: [Test; 0] = [];
+    // The rules every rule test is solved with, before the rule the test is for
+    pub const BASIC_RULES: &str = "N1H1N2H2LC";
+    // Boards the given rules have to solve quickly along with the basic rules, for rules that search
+    // far more on larger boards
+    pub const TIMING_TESTS: [(&str, Test); 1] = [("AXAWDB", size_tests::ALS_16X16)];
+    // The rule tests that need their rule once the basic rules are stuck, with the rule's code
+    pub const RULE_TESTS: [(&str, Test); 34] = [
+        ("XY", rule_tests::XY_WING),
+        ("XZ", rule_tests::XYZ_WING),
+        ("SF", rule_tests::SWORDFISH),
+        ("JF", rule_tests::JELLYFISH),
+        ("FX", rule_tests::FINNED_X_WING),
+        ("SX", rule_tests::SASHIMI_X_WING),
+        ("FS", rule_tests::FINNED_SWORDFISH),
+        ("SS", rule_tests::SASHIMI_SWORDFISH),
+        ("FJ", rule_tests::FINNED_JELLYFISH),
+        ("SJ", rule_tests::SASHIMI_JELLYFISH),
+        ("H3", rule_tests::HIDDEN_TRIPLE),
+        ("H4", rule_tests::HIDDEN_QUAD),
+        ("SC", rule_tests::SIMPLE_COLORING),
+        ("MC", rule_tests::MULTI_COLORING),
+        ("CF", rule_tests::CELL_FORCING_CHAIN),
+        ("UF", rule_tests::UNIT_FORCING_CHAIN),
+        ("NI", rule_tests::NISHIO),
+        ("XC", rule_tests::X_CYCLE),
+        ("AI", rule_tests::AIC),
+        ("GA", rule_tests::GROUPED_AIC),
+        ("U1", rule_tests::UNIQUE_RECTANGLE_1),
+        ("U2", rule_tests::UNIQUE_RECTANGLE_2),
+        ("U3", rule_tests::UNIQUE_RECTANGLE_3),
+        ("U4", rule_tests::UNIQUE_RECTANGLE_4),
+        ("BG", rule_tests::BUG_PLUS_ONE),
+        ("SK", rule_tests::SKYSCRAPER),
+        ("KT", rule_tests::TWO_STRING_KITE),
+        ("ER", rule_tests::EMPTY_RECTANGLE),
+        ("WW", rule_tests::W_WING),
+        ("RP", rule_tests::REMOTE_PAIRS),
+        ("AX", rule_tests::ALS_XZ),
+        ("AW", rule_tests::ALS_XY_WING),
+        ("DB", rule_tests::DEATH_BLOSSOM),
+        ("SD", rule_tests::SUE_DE_COQ),
     ];
-    pub const ALL_UNSOLVED_TESTS: [Test; 2] = [rule_tests::HIDDEN_PAIR, rule_tests::XY_WING];
 }
 #[allow(dead_code)]
 pub mod easy_tests {
@@ -55,15 +187,15 @@ pub mod easy_tests {
 870060001
 013058000",
         answer: &[
-            [2, 9, 1, 8, 3, 6, 7, 5, 4],
-            [6, 5, 7, 9, 2, 4, 1, 8, 3],
-            [3, 8, 4, 5, 1, 7, 9, 2, 6],
-            [5, 4, 6, 1, 8, 2, 3, 7, 9],
-            [1, 3, 8, 7, 9, 5, 4, 6, 2],
-            [7, 2, 9, 6, 4, 3, 8, 1, 5],
-            [4, 6, 5, 3, 7, 1, 2, 9, 8],
-            [8, 7, 2, 4, 6, 9, 5, 3, 1],
-            [9, 1, 3, 2, 5, 8, 6, 4, 7],
+            &[2, 9, 1, 8, 3, 6, 7, 5, 4],
+            &[6, 5, 7, 9, 2, 4, 1, 8, 3],
+            &[3, 8, 4, 5, 1, 7, 9, 2, 6],
+            &[5, 4, 6, 1, 8, 2, 3, 7, 9],
+            &[1, 3, 8, 7, 9, 5, 4, 6, 2],
+            &[7, 2, 9, 6, 4, 3, 8, 1, 5],
+            &[4, 6, 5, 3, 7, 1, 2, 9, 8],
+            &[8, 7, 2, 4, 6, 9, 5, 3, 1],
+            &[9, 1, 3, 2, 5, 8, 6, 4, 7],
         ],
     };
     pub const TEST_2: Test = Test {
@@ -78,15 +210,15 @@ pub mod easy_tests {
 630009700
 040005930",
         answer: &[
-            [7, 9, 5, 2, 8, 6, 3, 4, 1],
-            [1, 6, 3, 9, 5, 4, 2, 8, 7],
-            [8, 2, 4, 3, 7, 1, 5, 9, 6],
-            [4, 7, 6, 8, 9, 2, 1, 5, 3],
-            [5, 1, 2, 6, 4, 3, 8, 7, 9],
-            [3, 8, 9, 5, 1, 7, 4, 6, 2],
-            [9, 5, 1, 7, 3, 8, 6, 2, 4],
-            [6, 3, 8, 4, 2, 9, 7, 1, 5],
-            [2, 4, 7, 1, 6, 5, 9, 3, 8],
+            &[7, 9, 5, 2, 8, 6, 3, 4, 1],
+            &[1, 6, 3, 9, 5, 4, 2, 8, 7],
+            &[8, 2, 4, 3, 7, 1, 5, 9, 6],
+            &[4, 7, 6, 8, 9, 2, 1, 5, 3],
+            &[5, 1, 2, 6, 4, 3, 8, 7, 9],
+            &[3, 8, 9, 5, 1, 7, 4, 6, 2],
+            &[9, 5, 1, 7, 3, 8, 6, 2, 4],
+            &[6, 3, 8, 4, 2, 9, 7, 1, 5],
+            &[2, 4, 7, 1, 6, 5, 9, 3, 8],
         ],
     };
     pub const TEST_3: Test = Test {
@@ -101,15 +233,15 @@ pub mod easy_tests {
 678000000
 000000000",
         answer: &[
-            [1, 2, 3, 4, 5, 6, 7, 8, 9],
-            [4, 5, 6, 7, 8, 9, 1, 2, 3],
-            [7, 8, 9, 1, 2, 3, 4, 5, 6],
-            [2, 3, 4, 5, 6, 7, 8, 9, 1],
-            [5, 6, 7, 8, 9, 1, 2, 3, 4],
-            [8, 9, 1, 2, 3, 4, 5, 6, 7],
-            [3, 4, 5, 6, 7, 8, 9, 1, 2],
-            [6, 7, 8, 9, 1, 2, 3, 4, 5],
-            [9, 1, 2, 3, 4, 5, 6, 7, 8],
+            &[1, 2, 3, 4, 5, 6, 7, 8, 9],
+            &[4, 5, 6, 7, 8, 9, 1, 2, 3],
+            &[7, 8, 9, 1, 2, 3, 4, 5, 6],
+            &[2, 3, 4, 5, 6, 7, 8, 9, 1],
+            &[5, 6, 7, 8, 9, 1, 2, 3, 4],
+            &[8, 9, 1, 2, 3, 4, 5, 6, 7],
+            &[3, 4, 5, 6, 7, 8, 9, 1, 2],
+            &[6, 7, 8, 9, 1, 2, 3, 4, 5],
+            &[9, 1, 2, 3, 4, 5, 6, 7, 8],
         ],
     };
 }
@@ -129,15 +261,15 @@ pub mod medium_tests {
 107005000
 560000000",
         answer: &[
-            [6, 7, 5, 9, 1, 3, 2, 8, 4],
-            [8, 1, 3, 2, 4, 6, 5, 7, 9],
-            [9, 4, 2, 7, 5, 8, 1, 6, 3],
-            [7, 8, 6, 5, 2, 9, 3, 4, 1],
-            [4, 5, 9, 1, 3, 7, 8, 2, 6],
-            [3, 2, 1, 8, 6, 4, 7, 9, 5],
-            [2, 3, 8, 6, 9, 1, 4, 5, 7],
-            [1, 9, 7, 4, 8, 5, 6, 3, 2],
-            [5, 6, 4, 3, 7, 2, 9, 1, 8],
+            &[6, 7, 5, 9, 1, 3, 2, 8, 4],
+            &[8, 1, 3, 2, 4, 6, 5, 7, 9],
+            &[9, 4, 2, 7, 5, 8, 1, 6, 3],
+            &[7, 8, 6, 5, 2, 9, 3, 4, 1],
+            &[4, 5, 9, 1, 3, 7, 8, 2, 6],
+            &[3, 2, 1, 8, 6, 4, 7, 9, 5],
+            &[2, 3, 8, 6, 9, 1, 4, 5, 7],
+            &[1, 9, 7, 4, 8, 5, 6, 3, 2],
+            &[5, 6, 4, 3, 7, 2, 9, 1, 8],
         ],
     };
     // Solved
@@ -153,15 +285,15 @@ pub mod medium_tests {
 580000000
 109007000",
         answer: &[
-            [7, 5, 4, 8, 3, 2, 9, 1, 6],
-            [6, 9, 3, 1, 7, 5, 2, 8, 4],
-            [2, 1, 8, 4, 9, 6, 5, 3, 7],
-            [8, 6, 7, 5, 2, 1, 3, 4, 9],
-            [9, 4, 5, 7, 6, 3, 8, 2, 1],
-            [3, 2, 1, 9, 4, 8, 6, 7, 5],
-            [4, 7, 6, 3, 8, 9, 1, 5, 2],
-            [5, 8, 2, 6, 1, 4, 7, 9, 3],
-            [1, 3, 9, 2, 5, 7, 4, 6, 8],
+            &[7, 5, 4, 8, 3, 2, 9, 1, 6],
+            &[6, 9, 3, 1, 7, 5, 2, 8, 4],
+            &[2, 1, 8, 4, 9, 6, 5, 3, 7],
+            &[8, 6, 7, 5, 2, 1, 3, 4, 9],
+            &[9, 4, 5, 7, 6, 3, 8, 2, 1],
+            &[3, 2, 1, 9, 4, 8, 6, 7, 5],
+            &[4, 7, 6, 3, 8, 9, 1, 5, 2],
+            &[5, 8, 2, 6, 1, 4, 7, 9, 3],
+            &[1, 3, 9, 2, 5, 7, 4, 6, 8],
         ],
     };
     // solved
@@ -177,15 +309,15 @@ pub mod medium_tests {
 009000002
 008009035",
         answer: &[
-            [8, 5, 3, 9, 7, 4, 9, 6, 1],
-            [9, 4, 2, 6, 3, 1, 5, 7, 8],
-            [6, 7, 1, 5, 2, 8, 3, 9, 4],
-            [2, 8, 4, 1, 9, 6, 7, 5, 3],
-            [3, 9, 7, 8, 5, 2, 1, 4, 6],
-            [1, 6, 5, 7, 4, 3, 8, 2, 9],
-            [4, 2, 6, 3, 8, 5, 9, 1, 7],
-            [5, 3, 9, 4, 1, 7, 6, 8, 2],
-            [7, 1, 8, 2, 6, 9, 4, 3, 5],
+            &[8, 5, 3, 9, 7, 4, 9, 6, 1],
+            &[9, 4, 2, 6, 3, 1, 5, 7, 8],
+            &[6, 7, 1, 5, 2, 8, 3, 9, 4],
+            &[2, 8, 4, 1, 9, 6, 7, 5, 3],
+            &[3, 9, 7, 8, 5, 2, 1, 4, 6],
+            &[1, 6, 5, 7, 4, 3, 8, 2, 9],
+            &[4, 2, 6, 3, 8, 5, 9, 1, 7],
+            &[5, 3, 9, 4, 1, 7, 6, 8, 2],
+            &[7, 1, 8, 2, 6, 9, 4, 3, 5],
         ],
     };
     // solved
@@ -201,15 +333,15 @@ pub mod medium_tests {
 700090000
 100082000",
         answer: &[
-            [5, 1, 3, 9, 4, 8, 2, 7, 6],
-            [9, 6, 8, 2, 3, 7, 5, 4, 1],
-            [4, 2, 7, 5, 1, 6, 3, 9, 8],
-            [8, 7, 4, 6, 5, 9, 1, 2, 3],
-            [2, 5, 9, 3, 7, 1, 8, 6, 4],
-            [6, 3, 1, 8, 2, 4, 7, 5, 9],
-            [3, 9, 2, 1, 6, 5, 4, 8, 7],
-            [7, 8, 5, 4, 9, 3, 6, 1, 2],
-            [1, 4, 6, 7, 8, 2, 9, 3, 5],
+            &[5, 1, 3, 9, 4, 8, 2, 7, 6],
+            &[9, 6, 8, 2, 3, 7, 5, 4, 1],
+            &[4, 2, 7, 5, 1, 6, 3, 9, 8],
+            &[8, 7, 4, 6, 5, 9, 1, 2, 3],
+            &[2, 5, 9, 3, 7, 1, 8, 6, 4],
+            &[6, 3, 1, 8, 2, 4, 7, 5, 9],
+            &[3, 9, 2, 1, 6, 5, 4, 8, 7],
+            &[7, 8, 5, 4, 9, 3, 6, 1, 2],
+            &[1, 4, 6, 7, 8, 2, 9, 3, 5],
         ],
     };
 }
@@ -229,15 +361,15 @@ pub mod hard_tests {
 005417000
 000000000",
         answer: &[
-            [6, 1, 4, 2, 8, 3, 9, 5, 7],
-            [5, 3, 7, 1, 6, 9, 4, 2, 8],
-            [2, 9, 8, 7, 5, 4, 3, 1, 6],
-            [7, 6, 9, 3, 4, 1, 2, 8, 5],
-            [1, 8, 2, 9, 7, 5, 6, 4, 3],
-            [4, 5, 3, 8, 2, 6, 7, 9, 1],
-            [8, 7, 6, 5, 9, 2, 1, 3, 4],
-            [3, 2, 5, 4, 1, 7, 8, 6, 9],
-            [9, 4, 1, 6, 3, 8, 5, 7, 2],
+            &[6, 1, 4, 2, 8, 3, 9, 5, 7],
+            &[5, 3, 7, 1, 6, 9, 4, 2, 8],
+            &[2, 9, 8, 7, 5, 4, 3, 1, 6],
+            &[7, 6, 9, 3, 4, 1, 2, 8, 5],
+            &[1, 8, 2, 9, 7, 5, 6, 4, 3],
+            &[4, 5, 3, 8, 2, 6, 7, 9, 1],
+            &[8, 7, 6, 5, 9, 2, 1, 3, 4],
+            &[3, 2, 5, 4, 1, 7, 8, 6, 9],
+            &[9, 4, 1, 6, 3, 8, 5, 7, 2],
         ],
     };
     // COMPLETED
@@ -253,15 +385,15 @@ pub mod hard_tests {
 000040970
 000090000",
         answer: &[
-            [1, 2, 5, 4, 6, 9, 8, 3, 7],
-            [8, 7, 6, 3, 5, 1, 4, 2, 9],
-            [4, 3, 9, 7, 8, 2, 1, 6, 5],
-            [3, 5, 8, 9, 2, 7, 6, 1, 4],
-            [7, 9, 1, 6, 3, 4, 2, 5, 8],
-            [2, 6, 4, 8, 1, 5, 7, 9, 3],
-            [9, 1, 3, 2, 7, 8, 5, 4, 6],
-            [6, 8, 2, 5, 4, 3, 9, 7, 1],
-            [5, 4, 7, 1, 9, 6, 3, 8, 2],
+            &[1, 2, 5, 4, 6, 9, 8, 3, 7],
+            &[8, 7, 6, 3, 5, 1, 4, 2, 9],
+            &[4, 3, 9, 7, 8, 2, 1, 6, 5],
+            &[3, 5, 8, 9, 2, 7, 6, 1, 4],
+            &[7, 9, 1, 6, 3, 4, 2, 5, 8],
+            &[2, 6, 4, 8, 1, 5, 7, 9, 3],
+            &[9, 1, 3, 2, 7, 8, 5, 4, 6],
+            &[6, 8, 2, 5, 4, 3, 9, 7, 1],
+            &[5, 4, 7, 1, 9, 6, 3, 8, 2],
         ],
     };
     // COMPLETED
@@ -277,15 +409,15 @@ pub mod hard_tests {
 790003000
 850010000",
         answer: &[
-            [6, 4, 8, 5, 2, 7, 9, 1, 3],
-            [2, 3, 9, 1, 4, 6, 7, 5, 8],
-            [5, 1, 7, 8, 3, 9, 4, 2, 6],
-            [9, 7, 4, 6, 5, 8, 2, 3, 1],
-            [1, 6, 5, 3, 9, 2, 8, 4, 7],
-            [3, 8, 2, 4, 7, 1, 6, 9, 5],
-            [4, 2, 3, 7, 8, 5, 1, 6, 9],
-            [7, 9, 1, 2, 6, 3, 5, 8, 4],
-            [8, 5, 6, 9, 1, 4, 3, 7, 2],
+            &[6, 4, 8, 5, 2, 7, 9, 1, 3],
+            &[2, 3, 9, 1, 4, 6, 7, 5, 8],
+            &[5, 1, 7, 8, 3, 9, 4, 2, 6],
+            &[9, 7, 4, 6, 5, 8, 2, 3, 1],
+            &[1, 6, 5, 3, 9, 2, 8, 4, 7],
+            &[3, 8, 2, 4, 7, 1, 6, 9, 5],
+            &[4, 2, 3, 7, 8, 5, 1, 6, 9],
+            &[7, 9, 1, 2, 6, 3, 5, 8, 4],
+            &[8, 5, 6, 9, 1, 4, 3, 7, 2],
         ],
     };
     // COMPLETED
@@ -301,15 +433,15 @@ pub mod hard_tests {
 070830050
 400600000",
         answer: &[
-            [1, 2, 7, 5, 4, 6, 3, 8, 9],
-            [8, 5, 6, 3, 7, 9, 1, 2, 4],
-            [9, 3, 4, 2, 8, 1, 5, 7, 6],
-            [2, 8, 1, 7, 6, 5, 4, 9, 3],
-            [7, 4, 3, 1, 9, 8, 2, 6, 5],
-            [5, 6, 9, 4, 2, 3, 7, 1, 8],
-            [3, 1, 8, 9, 5, 2, 6, 4, 7],
-            [6, 7, 2, 8, 3, 4, 9, 5, 1],
-            [4, 9, 5, 6, 1, 7, 8, 3, 2],
+            &[1, 2, 7, 5, 4, 6, 3, 8, 9],
+            &[8, 5, 6, 3, 7, 9, 1, 2, 4],
+            &[9, 3, 4, 2, 8, 1, 5, 7, 6],
+            &[2, 8, 1, 7, 6, 5, 4, 9, 3],
+            &[7, 4, 3, 1, 9, 8, 2, 6, 5],
+            &[5, 6, 9, 4, 2, 3, 7, 1, 8],
+            &[3, 1, 8, 9, 5, 2, 6, 4, 7],
+            &[6, 7, 2, 8, 3, 4, 9, 5, 1],
+            &[4, 9, 5, 6, 1, 7, 8, 3, 2],
         ],
     };
     pub const TEST_5: Test = Test {
@@ -324,15 +456,15 @@ pub mod hard_tests {
 046010002
 000000090",
         answer: &[
-            [4, 1, 2, 9, 3, 5, 8, 7, 6],
-            [5, 8, 3, 7, 4, 6, 2, 1, 9],
-            [7, 6, 9, 2, 8, 1, 3, 5, 4],
-            [6, 2, 4, 1, 7, 3, 9, 8, 5],
-            [8, 7, 1, 5, 9, 2, 4, 6, 3],
-            [3, 9, 5, 4, 6, 8, 7, 2, 1],
-            [2, 3, 8, 6, 5, 9, 1, 4, 7],
-            [9, 4, 6, 8, 1, 7, 5, 3, 2],
-            [1, 5, 7, 3, 2, 4, 6, 9, 8],
+            &[4, 1, 2, 9, 3, 5, 8, 7, 6],
+            &[5, 8, 3, 7, 4, 6, 2, 1, 9],
+            &[7, 6, 9, 2, 8, 1, 3, 5, 4],
+            &[6, 2, 4, 1, 7, 3, 9, 8, 5],
+            &[8, 7, 1, 5, 9, 2, 4, 6, 3],
+            &[3, 9, 5, 4, 6, 8, 7, 2, 1],
+            &[2, 3, 8, 6, 5, 9, 1, 4, 7],
+            &[9, 4, 6, 8, 1, 7, 5, 3, 2],
+            &[1, 5, 7, 3, 2, 4, 6, 9, 8],
         ],
     };
     pub const TEST_6: Test = Test {
@@ -347,15 +479,15 @@ pub mod hard_tests {
 040005080
 000006000",
         answer: &[
-            [3, 5, 6, 2, 9, 4, 8, 7, 1],
-            [1, 7, 8, 6, 5, 3, 4, 9, 2],
-            [4, 2, 9, 7, 8, 1, 3, 6, 5],
-            [8, 1, 2, 5, 4, 9, 6, 3, 7],
-            [6, 9, 5, 1, 3, 7, 2, 4, 8],
-            [7, 3, 4, 8, 6, 2, 1, 5, 9],
-            [2, 6, 3, 9, 7, 8, 5, 1, 4],
-            [9, 4, 1, 3, 2, 5, 7, 8, 6],
-            [5, 8, 7, 4, 1, 6, 9, 2, 3],
+            &[3, 5, 6, 2, 9, 4, 8, 7, 1],
+            &[1, 7, 8, 6, 5, 3, 4, 9, 2],
+            &[4, 2, 9, 7, 8, 1, 3, 6, 5],
+            &[8, 1, 2, 5, 4, 9, 6, 3, 7],
+            &[6, 9, 5, 1, 3, 7, 2, 4, 8],
+            &[7, 3, 4, 8, 6, 2, 1, 5, 9],
+            &[2, 6, 3, 9, 7, 8, 5, 1, 4],
+            &[9, 4, 1, 3, 2, 5, 7, 8, 6],
+            &[5, 8, 7, 4, 1, 6, 9, 2, 3],
         ],
     };
     pub const TEST_7: Test = Test {
@@ -370,15 +502,15 @@ pub mod hard_tests {
 020008007
 809000000",
         answer: &[
-            [9, 1, 8, 4, 2, 3, 7, 6, 5],
-            [7, 6, 2, 9, 1, 5, 8, 3, 4],
-            [5, 3, 4, 8, 7, 6, 2, 1, 9],
-            [4, 7, 6, 3, 8, 2, 9, 5, 1],
-            [3, 8, 1, 5, 9, 4, 6, 7, 2],
-            [2, 9, 5, 7, 6, 1, 4, 8, 3],
-            [1, 4, 7, 6, 5, 9, 3, 2, 8],
-            [6, 2, 3, 1, 4, 8, 5, 9, 7],
-            [8, 5, 9, 2, 3, 7, 1, 4, 6],
+            &[9, 1, 8, 4, 2, 3, 7, 6, 5],
+            &[7, 6, 2, 9, 1, 5, 8, 3, 4],
+            &[5, 3, 4, 8, 7, 6, 2, 1, 9],
+            &[4, 7, 6, 3, 8, 2, 9, 5, 1],
+            &[3, 8, 1, 5, 9, 4, 6, 7, 2],
+            &[2, 9, 5, 7, 6, 1, 4, 8, 3],
+            &[1, 4, 7, 6, 5, 9, 3, 2, 8],
+            &[6, 2, 3, 1, 4, 8, 5, 9, 7],
+            &[8, 5, 9, 2, 3, 7, 1, 4, 6],
         ],
     };
 }
@@ -397,15 +529,15 @@ pub mod rule_tests {
 062000100
 000060040",
         answer: &[
-            [4, 1, 6, 9, 5, 7, 2, 3, 8],
-            [9, 5, 3, 4, 2, 8, 7, 1, 6],
-            [2, 8, 7, 3, 1, 6, 9, 5, 4],
-            [8, 9, 4, 5, 7, 1, 3, 6, 2],
-            [3, 7, 1, 6, 4, 2, 5, 8, 9],
-            [6, 2, 5, 8, 9, 3, 4, 7, 1],
-            [7, 4, 8, 1, 3, 9, 6, 2, 5],
-            [5, 6, 2, 7, 8, 4, 1, 9, 3],
-            [1, 3, 9, 2, 6, 5, 8, 4, 7],
+            &[4, 1, 6, 9, 5, 7, 2, 3, 8],
+            &[9, 5, 3, 4, 2, 8, 7, 1, 6],
+            &[2, 8, 7, 3, 1, 6, 9, 5, 4],
+            &[8, 9, 4, 5, 7, 1, 3, 6, 2],
+            &[3, 7, 1, 6, 4, 2, 5, 8, 9],
+            &[6, 2, 5, 8, 9, 3, 4, 7, 1],
+            &[7, 4, 8, 1, 3, 9, 6, 2, 5],
+            &[5, 6, 2, 7, 8, 4, 1, 9, 3],
+            &[1, 3, 9, 2, 6, 5, 8, 4, 7],
         ],
     };
     pub const XY_WING: Test = Test {
@@ -420,15 +552,1063 @@ pub mod rule_tests {
 008304705
 000170803",
         answer: &[
-            [1, 2, 4, 6, 3, 7, 9, 5, 8],
-            [8, 9, 7, 4, 1, 5, 6, 3, 2],
-            [5, 6, 3, 2, 8, 9, 1, 4, 7],
-            [7, 8, 5, 9, 4, 1, 3, 2, 6],
-            [6, 3, 1, 7, 5, 2, 4, 8, 9],
-            [2, 4, 9, 8, 6, 3, 5, 7, 1],
-            [3, 7, 6, 5, 9, 8, 2, 1, 4],
-            [9, 1, 8, 3, 2, 4, 7, 6, 5],
-            [4, 5, 2, 1, 7, 6, 8, 9, 3],
+            &[1, 2, 4, 6, 3, 7, 9, 5, 8],
+            &[8, 9, 7, 4, 1, 5, 6, 3, 2],
+            &[5, 6, 3, 2, 8, 9, 1, 4, 7],
+            &[7, 8, 5, 9, 4, 1, 3, 2, 6],
+            &[6, 3, 1, 7, 5, 2, 4, 8, 9],
+            &[2, 4, 9, 8, 6, 3, 5, 7, 1],
+            &[3, 7, 6, 5, 9, 8, 2, 1, 4],
+            &[9, 1, 8, 3, 2, 4, 7, 6, 5],
+            &[4, 5, 2, 1, 7, 6, 8, 9, 3],
+        ],
+    };
+    pub const XYZ_WING: Test = Test {
+        board: "\
+800600000
+070000210
+900180700
+210970000
+760030100
+008000000
+002010006
+600050470
+040009800",
+        answer: &[
+            &[8, 2, 1, 6, 4, 7, 3, 5, 9],
+            &[4, 7, 6, 5, 9, 3, 2, 1, 8],
+            &[9, 3, 5, 1, 8, 2, 7, 6, 4],
+            &[2, 1, 4, 9, 7, 6, 5, 8, 3],
+            &[7, 6, 9, 8, 3, 5, 1, 4, 2],
+            &[3, 5, 8, 4, 2, 1, 6, 9, 7],
+            &[5, 8, 2, 7, 1, 4, 9, 3, 6],
+            &[6, 9, 3, 2, 5, 8, 4, 7, 1],
+            &[1, 4, 7, 3, 6, 9, 8, 2, 5],
+        ],
+    };
+    pub const SWORDFISH: Test = Test {
+        board: "\
+500310070
+004000200
+000509000
+002000706
+030006040
+900000300
+000145000
+003097400
+100000020",
+        answer: &[
+            &[5, 2, 9, 3, 1, 4, 6, 7, 8],
+            &[3, 1, 4, 7, 6, 8, 2, 5, 9],
+            &[7, 6, 8, 5, 2, 9, 1, 3, 4],
+            &[4, 5, 2, 8, 3, 1, 7, 9, 6],
+            &[8, 3, 1, 9, 7, 6, 5, 4, 2],
+            &[9, 7, 6, 4, 5, 2, 3, 8, 1],
+            &[2, 9, 7, 1, 4, 5, 8, 6, 3],
+            &[6, 8, 3, 2, 9, 7, 4, 1, 5],
+            &[1, 4, 5, 6, 8, 3, 9, 2, 7],
+        ],
+    };
+    pub const JELLYFISH: Test = Test {
+        board: "\
+000900004
+050043087
+000000500
+003000905
+280000003
+000706020
+520090700
+006500409
+340000000",
+        answer: &[
+            &[8, 1, 7, 9, 2, 5, 3, 6, 4],
+            &[9, 5, 2, 6, 4, 3, 1, 8, 7],
+            &[6, 3, 4, 8, 7, 1, 5, 9, 2],
+            &[7, 6, 3, 2, 1, 8, 9, 4, 5],
+            &[2, 8, 1, 4, 5, 9, 6, 7, 3],
+            &[4, 9, 5, 7, 3, 6, 8, 2, 1],
+            &[5, 2, 8, 3, 9, 4, 7, 1, 6],
+            &[1, 7, 6, 5, 8, 2, 4, 3, 9],
+            &[3, 4, 9, 1, 6, 7, 2, 5, 8],
+        ],
+    };
+    pub const FINNED_X_WING: Test = Test {
+        board: "\
+000009060
+300080100
+860400000
+000590200
+002800459
+000070000
+000000090
+400005076
+090060810",
+        answer: &[
+            &[5, 4, 1, 2, 3, 9, 7, 6, 8],
+            &[3, 2, 9, 6, 8, 7, 1, 4, 5],
+            &[8, 6, 7, 4, 5, 1, 9, 2, 3],
+            &[1, 8, 6, 5, 9, 4, 2, 3, 7],
+            &[7, 3, 2, 8, 1, 6, 4, 5, 9],
+            &[9, 5, 4, 3, 7, 2, 6, 8, 1],
+            &[6, 7, 3, 1, 4, 8, 5, 9, 2],
+            &[4, 1, 8, 9, 2, 5, 3, 7, 6],
+            &[2, 9, 5, 7, 6, 3, 8, 1, 4],
+        ],
+    };
+    pub const SASHIMI_X_WING: Test = Test {
+        board: "\
+050000000
+780300060
+000480903
+000000000
+100090005
+025600400
+500740009
+007000010
+830000600",
+        answer: &[
+            &[9, 5, 3, 1, 6, 2, 7, 8, 4],
+            &[7, 8, 4, 3, 5, 9, 2, 6, 1],
+            &[2, 6, 1, 4, 8, 7, 9, 5, 3],
+            &[4, 9, 8, 5, 2, 3, 1, 7, 6],
+            &[1, 7, 6, 8, 9, 4, 3, 2, 5],
+            &[3, 2, 5, 6, 7, 1, 4, 9, 8],
+            &[5, 1, 2, 7, 4, 6, 8, 3, 9],
+            &[6, 4, 7, 9, 3, 8, 5, 1, 2],
+            &[8, 3, 9, 2, 1, 5, 6, 4, 7],
+        ],
+    };
+    pub const FINNED_SWORDFISH: Test = Test {
+        board: "\
+000020000
+007050380
+900006000
+630005000
+008600700
+000090002
+001579003
+079003260
+000000000",
+        answer: &[
+            &[3, 8, 6, 1, 2, 7, 9, 5, 4],
+            &[1, 2, 7, 9, 5, 4, 3, 8, 6],
+            &[9, 5, 4, 3, 8, 6, 1, 2, 7],
+            &[6, 3, 2, 7, 1, 5, 4, 9, 8],
+            &[4, 9, 8, 6, 3, 2, 7, 1, 5],
+            &[7, 1, 5, 4, 9, 8, 6, 3, 2],
+            &[2, 6, 1, 5, 7, 9, 8, 4, 3],
+            &[5, 7, 9, 8, 4, 3, 2, 6, 1],
+            &[8, 4, 3, 2, 6, 1, 5, 7, 9],
+        ],
+    };
+    pub const SASHIMI_SWORDFISH: Test = Test {
+        board: "\
+007016000
+002000080
+060400900
+900080004
+000047000
+070000260
+200705009
+000098406
+100000000",
+        answer: &[
+            &[5, 9, 7, 8, 1, 6, 3, 4, 2],
+            &[4, 3, 2, 5, 7, 9, 6, 8, 1],
+            &[8, 6, 1, 4, 2, 3, 9, 5, 7],
+            &[9, 1, 5, 6, 8, 2, 7, 3, 4],
+            &[6, 2, 8, 3, 4, 7, 1, 9, 5],
+            &[3, 7, 4, 9, 5, 1, 2, 6, 8],
+            &[2, 4, 6, 7, 3, 5, 8, 1, 9],
+            &[7, 5, 3, 1, 9, 8, 4, 2, 6],
+            &[1, 8, 9, 2, 6, 4, 5, 7, 3],
+        ],
+    };
+    pub const FINNED_JELLYFISH: Test = Test {
+        board: "\
+007402000
+020985076
+000000010
+004001065
+506000002
+010008000
+000009038
+105000020
+060004000",
+        answer: &[
+            &[6, 3, 7, 4, 1, 2, 5, 8, 9],
+            &[4, 2, 1, 9, 8, 5, 3, 7, 6],
+            &[9, 5, 8, 6, 7, 3, 2, 1, 4],
+            &[3, 7, 4, 2, 9, 1, 8, 6, 5],
+            &[5, 8, 6, 3, 4, 7, 1, 9, 2],
+            &[2, 1, 9, 5, 6, 8, 7, 4, 3],
+            &[7, 4, 2, 1, 5, 9, 6, 3, 8],
+            &[1, 9, 5, 8, 3, 6, 4, 2, 7],
+            &[8, 6, 3, 7, 2, 4, 9, 5, 1],
+        ],
+    };
+    pub const SASHIMI_JELLYFISH: Test = Test {
+        board: "\
+800000003
+300060500
+004001060
+000008010
+080409000
+000070056
+060034087
+040000005
+728090000",
+        answer: &[
+            &[8, 7, 6, 9, 4, 5, 1, 2, 3],
+            &[3, 1, 2, 8, 6, 7, 5, 4, 9],
+            &[9, 5, 4, 3, 2, 1, 7, 6, 8],
+            &[2, 3, 7, 6, 5, 8, 9, 1, 4],
+            &[6, 8, 5, 4, 1, 9, 3, 7, 2],
+            &[4, 9, 1, 2, 7, 3, 8, 5, 6],
+            &[5, 6, 9, 1, 3, 4, 2, 8, 7],
+            &[1, 4, 3, 7, 8, 2, 6, 9, 5],
+            &[7, 2, 8, 5, 9, 6, 4, 3, 1],
+        ],
+    };
+    pub const HIDDEN_TRIPLE: Test = Test {
+        board: "\
+000060804
+200050000
+000001060
+008310070
+000580213
+000004000
+050003000
+000740008
+039000007",
+        answer: &[
+            &[9, 1, 3, 2, 6, 7, 8, 5, 4],
+            &[2, 7, 6, 4, 5, 8, 1, 3, 9],
+            &[4, 8, 5, 9, 3, 1, 7, 6, 2],
+            &[5, 9, 8, 3, 1, 2, 4, 7, 6],
+            &[6, 4, 7, 5, 8, 9, 2, 1, 3],
+            &[3, 2, 1, 6, 7, 4, 9, 8, 5],
+            &[7, 5, 4, 8, 9, 3, 6, 2, 1],
+            &[1, 6, 2, 7, 4, 5, 3, 9, 8],
+            &[8, 3, 9, 1, 2, 6, 5, 4, 7],
+        ],
+    };
+    pub const HIDDEN_QUAD: Test = Test {
+        board: "\
+500300840
+004000003
+060000095
+003800000
+800752000
+020004008
+000000070
+030460009
+050000104",
+        answer: &[
+            &[5, 7, 9, 3, 2, 6, 8, 4, 1],
+            &[1, 8, 4, 5, 9, 7, 6, 2, 3],
+            &[3, 6, 2, 1, 4, 8, 7, 9, 5],
+            &[6, 4, 3, 8, 1, 9, 2, 5, 7],
+            &[8, 9, 1, 7, 5, 2, 4, 3, 6],
+            &[7, 2, 5, 6, 3, 4, 9, 1, 8],
+            &[4, 1, 6, 9, 8, 5, 3, 7, 2],
+            &[2, 3, 7, 4, 6, 1, 5, 8, 9],
+            &[9, 5, 8, 2, 7, 3, 1, 6, 4],
+        ],
+    };
+    pub const SIMPLE_COLORING: Test = Test {
+        board: "\
+000820500
+004003002
+000504106
+900000300
+010007405
+056000000
+001030009
+200705014
+700000200",
+        answer: &[
+            &[1, 6, 3, 8, 2, 9, 5, 4, 7],
+            &[5, 7, 4, 1, 6, 3, 8, 9, 2],
+            &[8, 2, 9, 5, 7, 4, 1, 3, 6],
+            &[9, 8, 7, 4, 5, 6, 3, 2, 1],
+            &[3, 1, 2, 9, 8, 7, 4, 6, 5],
+            &[4, 5, 6, 3, 1, 2, 9, 7, 8],
+            &[6, 4, 1, 2, 3, 8, 7, 5, 9],
+            &[2, 3, 8, 7, 9, 5, 6, 1, 4],
+            &[7, 9, 5, 6, 4, 1, 2, 8, 3],
+        ],
+    };
+    pub const MULTI_COLORING: Test = Test {
+        board: "\
+000001200
+100450000
+004700003
+023064090
+019008000
+000000800
+000000910
+900043000
+070100024",
+        answer: &[
+            &[6, 9, 7, 8, 3, 1, 2, 4, 5],
+            &[1, 3, 8, 4, 5, 2, 6, 7, 9],
+            &[2, 5, 4, 7, 9, 6, 1, 8, 3],
+            &[8, 2, 3, 5, 6, 4, 7, 9, 1],
+            &[7, 1, 9, 3, 2, 8, 4, 5, 6],
+            &[4, 6, 5, 9, 1, 7, 8, 3, 2],
+            &[3, 4, 2, 6, 7, 5, 9, 1, 8],
+            &[9, 8, 1, 2, 4, 3, 5, 6, 7],
+            &[5, 7, 6, 1, 8, 9, 3, 2, 4],
+        ],
+    };
+    pub const CELL_FORCING_CHAIN: Test = Test {
+        board: "\
+000000020
+000897000
+405160000
+847006100
+000000040
+102700003
+080000006
+006000704
+700031000",
+        answer: &[
+            &[9, 7, 8, 5, 4, 3, 6, 2, 1],
+            &[6, 2, 1, 8, 9, 7, 4, 3, 5],
+            &[4, 3, 5, 1, 6, 2, 9, 7, 8],
+            &[8, 4, 7, 3, 5, 6, 1, 9, 2],
+            &[5, 6, 3, 2, 1, 9, 8, 4, 7],
+            &[1, 9, 2, 7, 8, 4, 5, 6, 3],
+            &[2, 8, 9, 4, 7, 5, 3, 1, 6],
+            &[3, 1, 6, 9, 2, 8, 7, 5, 4],
+            &[7, 5, 4, 6, 3, 1, 2, 8, 9],
+        ],
+    };
+    pub const UNIT_FORCING_CHAIN: Test = Test {
+        board: "\
+010009000
+000000050
+000052983
+060005070
+000600030
+003900400
+102500000
+000807004
+006020000",
+        answer: &[
+            &[2, 1, 5, 3, 8, 9, 6, 4, 7],
+            &[9, 3, 8, 7, 4, 6, 2, 5, 1],
+            &[6, 7, 4, 1, 5, 2, 9, 8, 3],
+            &[4, 6, 1, 2, 3, 5, 8, 7, 9],
+            &[8, 9, 7, 6, 1, 4, 5, 3, 2],
+            &[5, 2, 3, 9, 7, 8, 4, 1, 6],
+            &[1, 4, 2, 5, 9, 3, 7, 6, 8],
+            &[3, 5, 9, 8, 6, 7, 1, 2, 4],
+            &[7, 8, 6, 4, 2, 1, 3, 9, 5],
+        ],
+    };
+    pub const NISHIO: Test = Test {
+        board: "\
+040500070
+020600800
+000070016
+206000038
+000009060
+000007104
+108020607
+004000000
+000006501",
+        answer: &[
+            &[6, 4, 1, 5, 9, 8, 2, 7, 3],
+            &[3, 2, 7, 6, 1, 4, 8, 9, 5],
+            &[5, 8, 9, 3, 7, 2, 4, 1, 6],
+            &[2, 7, 6, 4, 5, 1, 9, 3, 8],
+            &[4, 1, 5, 8, 3, 9, 7, 6, 2],
+            &[8, 9, 3, 2, 6, 7, 1, 5, 4],
+            &[1, 5, 8, 9, 2, 3, 6, 4, 7],
+            &[7, 6, 4, 1, 8, 5, 3, 2, 9],
+            &[9, 3, 2, 7, 4, 6, 5, 8, 1],
+        ],
+    };
+    pub const X_CYCLE: Test = Test {
+        board: "\
+030609000
+000034000
+609820000
+000500900
+041000030
+006000001
+900060800
+000000105
+003000072",
+        answer: &[
+            &[1, 3, 4, 6, 5, 9, 2, 8, 7],
+            &[8, 2, 7, 1, 3, 4, 5, 6, 9],
+            &[6, 5, 9, 8, 2, 7, 3, 1, 4],
+            &[3, 7, 8, 5, 4, 1, 9, 2, 6],
+            &[5, 4, 1, 2, 9, 6, 7, 3, 8],
+            &[2, 9, 6, 3, 7, 8, 4, 5, 1],
+            &[9, 1, 5, 7, 6, 2, 8, 4, 3],
+            &[7, 6, 2, 4, 8, 3, 1, 9, 5],
+            &[4, 8, 3, 9, 1, 5, 6, 7, 2],
+        ],
+    };
+    pub const AIC: Test = Test {
+        board: "\
+065423100
+000000005
+010580024
+107000000
+002010460
+000209000
+000390800
+900008206
+000040000",
+        answer: &[
+            &[8, 6, 5, 4, 2, 3, 1, 7, 9],
+            &[2, 3, 4, 9, 7, 1, 6, 8, 5],
+            &[7, 1, 9, 5, 8, 6, 3, 2, 4],
+            &[1, 5, 7, 8, 6, 4, 9, 3, 2],
+            &[3, 9, 2, 7, 1, 5, 4, 6, 8],
+            &[6, 4, 8, 2, 3, 9, 5, 1, 7],
+            &[4, 2, 6, 3, 9, 7, 8, 5, 1],
+            &[9, 7, 3, 1, 5, 8, 2, 4, 6],
+            &[5, 8, 1, 6, 4, 2, 7, 9, 3],
+        ],
+    };
+    pub const GROUPED_AIC: Test = Test {
+        board: "\
+008063004
+006000000
+201900000
+030048000
+000090010
+079300080
+902000000
+010820700
+000104000",
+        answer: &[
+            &[7, 9, 8, 5, 6, 3, 1, 2, 4],
+            &[3, 5, 6, 4, 1, 2, 8, 7, 9],
+            &[2, 4, 1, 9, 8, 7, 6, 3, 5],
+            &[1, 3, 5, 2, 4, 8, 9, 6, 7],
+            &[8, 2, 4, 7, 9, 6, 5, 1, 3],
+            &[6, 7, 9, 3, 5, 1, 4, 8, 2],
+            &[9, 8, 2, 6, 7, 5, 3, 4, 1],
+            &[4, 1, 3, 8, 2, 9, 7, 5, 6],
+            &[5, 6, 7, 1, 3, 4, 2, 9, 8],
+        ],
+    };
+    pub const SKYSCRAPER: Test = Test {
+        board: "\
+100200560
+050001000
+307000000
+000002615
+278000000
+000000080
+032800000
+000146000
+000000007",
+        answer: &[
+            &[1, 4, 9, 2, 7, 3, 5, 6, 8],
+            &[8, 5, 6, 4, 9, 1, 2, 7, 3],
+            &[3, 2, 7, 5, 6, 8, 4, 9, 1],
+            &[4, 9, 3, 7, 8, 2, 6, 1, 5],
+            &[2, 7, 8, 6, 1, 5, 9, 3, 4],
+            &[5, 6, 1, 9, 3, 4, 7, 8, 2],
+            &[9, 3, 2, 8, 5, 7, 1, 4, 6],
+            &[7, 8, 5, 1, 4, 6, 3, 2, 9],
+            &[6, 1, 4, 3, 2, 9, 8, 5, 7],
+        ],
+    };
+    pub const TWO_STRING_KITE: Test = Test {
+        board: "\
+000000000
+061008000
+020500406
+000006085
+604002000
+000019007
+080000000
+100600520
+032001008",
+        answer: &[
+            &[3, 9, 5, 1, 6, 4, 8, 7, 2],
+            &[4, 6, 1, 7, 2, 8, 3, 5, 9],
+            &[8, 2, 7, 5, 9, 3, 4, 1, 6],
+            &[9, 1, 3, 4, 7, 6, 2, 8, 5],
+            &[6, 7, 4, 8, 5, 2, 9, 3, 1],
+            &[2, 5, 8, 3, 1, 9, 6, 4, 7],
+            &[7, 8, 6, 2, 3, 5, 1, 9, 4],
+            &[1, 4, 9, 6, 8, 7, 5, 2, 3],
+            &[5, 3, 2, 9, 4, 1, 7, 6, 8],
+        ],
+    };
+    pub const EMPTY_RECTANGLE: Test = Test {
+        board: "\
+000050012
+000000600
+172000008
+200040000
+030000006
+850600309
+003000870
+040070000
+000002095",
+        answer: &[
+            &[3, 6, 4, 8, 5, 9, 7, 1, 2],
+            &[5, 9, 8, 2, 1, 7, 6, 3, 4],
+            &[1, 7, 2, 4, 3, 6, 9, 5, 8],
+            &[2, 1, 6, 9, 4, 3, 5, 8, 7],
+            &[4, 3, 9, 7, 8, 5, 1, 2, 6],
+            &[8, 5, 7, 6, 2, 1, 3, 4, 9],
+            &[6, 2, 3, 5, 9, 4, 8, 7, 1],
+            &[9, 4, 5, 1, 7, 8, 2, 6, 3],
+            &[7, 8, 1, 3, 6, 2, 4, 9, 5],
+        ],
+    };
+    pub const W_WING: Test = Test {
+        board: "\
+904000000
+000001005
+000670001
+032100000
+000094000
+000803100
+008006050
+060309000
+000000410",
+        answer: &[
+            &[9, 1, 4, 2, 3, 5, 6, 7, 8],
+            &[6, 8, 7, 9, 4, 1, 2, 3, 5],
+            &[2, 5, 3, 6, 7, 8, 9, 4, 1],
+            &[8, 3, 2, 1, 6, 7, 5, 9, 4],
+            &[1, 7, 6, 5, 9, 4, 8, 2, 3],
+            &[5, 4, 9, 8, 2, 3, 1, 6, 7],
+            &[7, 2, 8, 4, 1, 6, 3, 5, 9],
+            &[4, 6, 1, 3, 5, 9, 7, 8, 2],
+            &[3, 9, 5, 7, 8, 2, 4, 1, 6],
+        ],
+    };
+    pub const REMOTE_PAIRS: Test = Test {
+        board: "\
+000830000
+000209050
+210005000
+900000002
+403971000
+000003000
+090100000
+008000075
+050600320",
+        answer: &[
+            &[7, 6, 5, 8, 3, 4, 2, 9, 1],
+            &[8, 3, 4, 2, 1, 9, 7, 5, 6],
+            &[2, 1, 9, 7, 6, 5, 8, 4, 3],
+            &[9, 7, 1, 5, 8, 6, 4, 3, 2],
+            &[4, 2, 3, 9, 7, 1, 5, 6, 8],
+            &[5, 8, 6, 4, 2, 3, 9, 1, 7],
+            &[3, 9, 2, 1, 5, 7, 6, 8, 4],
+            &[6, 4, 8, 3, 9, 2, 1, 7, 5],
+            &[1, 5, 7, 6, 4, 8, 3, 2, 9],
+        ],
+    };
+    pub const ALS_XZ: Test = Test {
+        board: "\
+070003028
+500000400
+000000306
+032009000
+000060000
+810000203
+456000000
+000007000
+100040002",
+        answer: &[
+            &[9, 7, 4, 6, 5, 3, 1, 2, 8],
+            &[5, 6, 3, 8, 2, 1, 4, 9, 7],
+            &[2, 8, 1, 7, 9, 4, 3, 5, 6],
+            &[6, 3, 2, 1, 8, 9, 5, 7, 4],
+            &[7, 4, 5, 3, 6, 2, 9, 8, 1],
+            &[8, 1, 9, 4, 7, 5, 2, 6, 3],
+            &[4, 5, 6, 2, 3, 8, 7, 1, 9],
+            &[3, 2, 8, 9, 1, 7, 6, 4, 5],
+            &[1, 9, 7, 5, 4, 6, 8, 3, 2],
+        ],
+    };
+    pub const ALS_XY_WING: Test = Test {
+        board: "\
+000000126
+903100580
+002000030
+080000205
+009060800
+006007000
+007000050
+065073900
+090008003",
+        answer: &[
+            &[4, 5, 8, 7, 3, 9, 1, 2, 6],
+            &[9, 7, 3, 1, 2, 6, 5, 8, 4],
+            &[6, 1, 2, 5, 8, 4, 7, 3, 9],
+            &[7, 8, 4, 3, 9, 1, 2, 6, 5],
+            &[1, 3, 9, 2, 6, 5, 8, 4, 7],
+            &[5, 2, 6, 8, 4, 7, 3, 9, 1],
+            &[3, 4, 7, 9, 1, 2, 6, 5, 8],
+            &[8, 6, 5, 4, 7, 3, 9, 1, 2],
+            &[2, 9, 1, 6, 5, 8, 4, 7, 3],
+        ],
+    };
+    pub const DEATH_BLOSSOM: Test = Test {
+        board: "\
+000239000
+000000006
+400085302
+300070009
+090000710
+000060004
+000002570
+200000003
+080090000",
+        answer: &[
+            &[5, 6, 8, 2, 3, 9, 1, 4, 7],
+            &[9, 2, 3, 7, 1, 4, 8, 5, 6],
+            &[4, 7, 1, 6, 8, 5, 3, 9, 2],
+            &[3, 4, 2, 5, 7, 1, 6, 8, 9],
+            &[8, 9, 6, 4, 2, 3, 7, 1, 5],
+            &[1, 5, 7, 9, 6, 8, 2, 3, 4],
+            &[6, 3, 9, 1, 4, 2, 5, 7, 8],
+            &[2, 1, 4, 8, 5, 7, 9, 6, 3],
+            &[7, 8, 5, 3, 9, 6, 4, 2, 1],
+        ],
+    };
+    pub const SUE_DE_COQ: Test = Test {
+        board: "\
+050000080
+040052010
+106000000
+700000000
+000500209
+038000000
+097010000
+000690030
+000205900",
+        answer: &[
+            &[9, 5, 2, 1, 7, 6, 4, 8, 3],
+            &[8, 4, 3, 9, 5, 2, 7, 1, 6],
+            &[1, 7, 6, 8, 4, 3, 5, 9, 2],
+            &[7, 2, 9, 4, 6, 1, 3, 5, 8],
+            &[4, 6, 1, 5, 3, 8, 2, 7, 9],
+            &[5, 3, 8, 7, 2, 9, 6, 4, 1],
+            &[6, 9, 7, 3, 1, 4, 8, 2, 5],
+            &[2, 8, 5, 6, 9, 7, 1, 3, 4],
+            &[3, 1, 4, 2, 8, 5, 9, 6, 7],
+        ],
+    };
+    pub const UNIQUE_RECTANGLE_1: Test = Test {
+        board: "\
+500096000
+010000520
+070000001
+251900000
+080000009
+097030010
+000000786
+100008053
+060000000",
+        answer: &[
+            &[5, 4, 2, 1, 9, 6, 8, 3, 7],
+            &[9, 1, 6, 7, 8, 3, 5, 2, 4],
+            &[8, 7, 3, 4, 5, 2, 9, 6, 1],
+            &[2, 5, 1, 9, 6, 7, 3, 4, 8],
+            &[3, 8, 4, 5, 2, 1, 6, 7, 9],
+            &[6, 9, 7, 8, 3, 4, 2, 1, 5],
+            &[4, 3, 5, 2, 1, 9, 7, 8, 6],
+            &[1, 2, 9, 6, 7, 8, 4, 5, 3],
+            &[7, 6, 8, 3, 4, 5, 1, 9, 2],
+        ],
+    };
+    pub const UNIQUE_RECTANGLE_2: Test = Test {
+        board: "\
+000000004
+000009512
+000830000
+004006805
+600080700
+002400000
+000000900
+840390000
+000001050",
+        answer: &[
+            &[9, 6, 7, 1, 5, 2, 3, 8, 4],
+            &[4, 3, 8, 7, 6, 9, 5, 1, 2],
+            &[2, 5, 1, 8, 3, 4, 6, 7, 9],
+            &[3, 7, 4, 9, 1, 6, 8, 2, 5],
+            &[6, 1, 9, 2, 8, 5, 7, 4, 3],
+            &[5, 8, 2, 4, 7, 3, 1, 9, 6],
+            &[1, 2, 6, 5, 4, 8, 9, 3, 7],
+            &[8, 4, 5, 3, 9, 7, 2, 6, 1],
+            &[7, 9, 3, 6, 2, 1, 4, 5, 8],
+        ],
+    };
+    pub const UNIQUE_RECTANGLE_3: Test = Test {
+        board: "\
+000000000
+508007109
+070300500
+807002060
+306780000
+009000005
+005070000
+000104000
+940503000",
+        answer: &[
+            &[1, 9, 3, 8, 5, 6, 2, 4, 7],
+            &[5, 6, 8, 4, 2, 7, 1, 3, 9],
+            &[2, 7, 4, 3, 1, 9, 5, 8, 6],
+            &[8, 5, 7, 9, 4, 2, 3, 6, 1],
+            &[3, 1, 6, 7, 8, 5, 4, 9, 2],
+            &[4, 2, 9, 6, 3, 1, 8, 7, 5],
+            &[6, 3, 5, 2, 7, 8, 9, 1, 4],
+            &[7, 8, 2, 1, 9, 4, 6, 5, 3],
+            &[9, 4, 1, 5, 6, 3, 7, 2, 8],
+        ],
+    };
+    pub const UNIQUE_RECTANGLE_4: Test = Test {
+        board: "\
+040007000
+000001580
+000020001
+000000100
+090070004
+020008050
+009000072
+070009810
+005030009",
+        answer: &[
+            &[9, 4, 1, 8, 5, 7, 2, 3, 6],
+            &[2, 3, 6, 4, 9, 1, 5, 8, 7],
+            &[5, 8, 7, 3, 2, 6, 9, 4, 1],
+            &[7, 5, 3, 2, 6, 4, 1, 9, 8],
+            &[1, 9, 8, 5, 7, 3, 6, 2, 4],
+            &[6, 2, 4, 9, 1, 8, 7, 5, 3],
+            &[4, 6, 9, 1, 8, 5, 3, 7, 2],
+            &[3, 7, 2, 6, 4, 9, 8, 1, 5],
+            &[8, 1, 5, 7, 3, 2, 4, 6, 9],
+        ],
+    };
+    pub const BUG_PLUS_ONE: Test = Test {
+        board: "\
+000700046
+130000800
+000009000
+000900310
+003406007
+700001008
+400600070
+980000054
+000100600",
+        answer: &[
+            &[2, 9, 8, 7, 1, 3, 5, 4, 6],
+            &[1, 3, 7, 5, 6, 4, 8, 9, 2],
+            &[6, 4, 5, 8, 2, 9, 7, 3, 1],
+            &[8, 6, 4, 9, 7, 2, 3, 1, 5],
+            &[5, 1, 3, 4, 8, 6, 9, 2, 7],
+            &[7, 2, 9, 3, 5, 1, 4, 6, 8],
+            &[4, 5, 1, 6, 9, 8, 2, 7, 3],
+            &[9, 8, 6, 2, 3, 7, 1, 5, 4],
+            &[3, 7, 2, 1, 4, 5, 6, 8, 9],
+        ],
+    };
+    // Has more than one solution, with rectangles the uniqueness rules would wrongly use if they
+    // didn't wait for the board to be checked
+    pub const MULTIPLE_SOLUTIONS: &str = "\
+040007000
+000001580
+000020001
+000000100
+090070004
+020008050
+009000072
+070009810
+005030000";
+    // A Sudoku-X board with one solution, where the basic rules leave rectangles with a corner on a
+    // diagonal, which swapping values around would break
+    pub const SUDOKU_X_RECTANGLES: Test = Test {
+        board: "\
+X
+106800000
+005000070
+000070000
+009000000
+007100040
+000008000
+040002000
+900000204
+000500000",
+        answer: &[
+            &[1, 7, 6, 8, 5, 3, 4, 9, 2],
+            &[3, 2, 5, 4, 1, 9, 8, 7, 6],
+            &[8, 9, 4, 2, 7, 6, 1, 3, 5],
+            &[5, 8, 9, 7, 6, 4, 3, 2, 1],
+            &[2, 6, 7, 1, 3, 5, 9, 4, 8],
+            &[4, 1, 3, 9, 2, 8, 6, 5, 7],
+            &[7, 4, 8, 6, 9, 2, 5, 1, 3],
+            &[9, 5, 1, 3, 8, 7, 2, 6, 4],
+            &[6, 3, 2, 5, 4, 1, 7, 8, 9],
+        ],
+    };
+}
+#[allow(dead_code)]
+pub mod size_tests {
+    use super::*;
+    pub const TEST_4X4: Test = Test {
+        board: "\
+0000
+0302
+4000
+0200",
+        answer: &[&[2, 4, 3, 1], &[1, 3, 4, 2], &[4, 1, 2, 3], &[3, 2, 1, 4]],
+    };
+    pub const TEST_6X6: Test = Test {
+        board: "\
+010600
+000050
+020400
+006002
+000040
+030206",
+        answer: &[
+            &[3, 1, 5, 6, 2, 4],
+            &[6, 4, 2, 3, 5, 1],
+            &[1, 2, 3, 4, 6, 5],
+            &[4, 5, 6, 1, 3, 2],
+            &[2, 6, 1, 5, 4, 3],
+            &[5, 3, 4, 2, 1, 6],
+        ],
+    };
+    pub const TEST_12X12: Test = Test {
+        board: "\
+8010000049A0
+CB0700000001
+005000007000
+0000C0A00054
+007C00000B06
+050081060000
+14900620050C
+2000A70C3000
+000A04000200
+4CA00003200B
+70B200000600
+000008005000",
+        answer: &[
+            &[8, 3, 1, 6, 7, 11, 12, 2, 4, 9, 10, 5],
+            &[12, 11, 2, 7, 4, 10, 9, 5, 6, 8, 3, 1],
+            &[9, 10, 5, 4, 6, 3, 8, 1, 7, 12, 11, 2],
+            &[11, 1, 6, 8, 12, 2, 10, 7, 9, 3, 5, 4],
+            &[10, 2, 7, 12, 9, 5, 3, 4, 8, 11, 1, 6],
+            &[3, 5, 4, 9, 8, 1, 11, 6, 12, 10, 2, 7],
+            &[1, 4, 9, 3, 11, 6, 2, 8, 10, 5, 7, 12],
+            &[2, 6, 8, 11, 10, 7, 5, 12, 3, 1, 4, 9],
+            &[5, 7, 12, 10, 3, 4, 1, 9, 11, 2, 6, 8],
+            &[4, 12, 10, 5, 1, 9, 6, 3, 2, 7, 8, 11],
+            &[7, 8, 11, 2, 5, 12, 4, 10, 1, 6, 9, 3],
+            &[6, 9, 3, 1, 2, 8, 7, 11, 5, 4, 12, 10],
+        ],
+    };
+    pub const TEST_16X16: Test = Test {
+        board: "\
+3B000F00G0240009
+0C1008000A090000
+8G40006000700DC0
+000E70B500D00000
+E000600A50000010
+0000C20040G8700A
+00F00E080700D000
+7906B000020F0040
+00E090000C0D0102
+00D50G008040B9A0
+BA000000F0120000
+G000460E00000530
+0000A000D00C4F00
+00C3002G09060A70
+000000DC00000000
+4200000000AB1300",
+        answer: &[
+            &[3, 11, 5, 7, 13, 15, 12, 1, 16, 8, 2, 4, 10, 14, 6, 9],
+            &[15, 12, 1, 13, 2, 8, 16, 4, 6, 10, 14, 9, 3, 7, 11, 5],
+            &[8, 16, 4, 2, 14, 10, 6, 9, 11, 3, 7, 5, 15, 13, 12, 1],
+            &[10, 6, 9, 14, 7, 3, 11, 5, 12, 15, 13, 1, 8, 2, 16, 4],
+            &[14, 4, 8, 16, 6, 7, 9, 10, 5, 13, 11, 3, 2, 12, 1, 15],
+            &[13, 5, 3, 11, 12, 2, 1, 15, 4, 14, 16, 8, 7, 6, 9, 10],
+            &[2, 1, 15, 12, 16, 14, 4, 8, 9, 7, 6, 10, 13, 11, 5, 3],
+            &[7, 9, 10, 6, 11, 13, 5, 3, 1, 2, 12, 15, 14, 16, 4, 8],
+            &[6, 8, 14, 4, 9, 11, 10, 7, 3, 12, 5, 13, 16, 1, 15, 2],
+            &[12, 3, 13, 5, 1, 16, 15, 2, 8, 6, 4, 14, 11, 9, 10, 7],
+            &[11, 10, 7, 9, 5, 12, 3, 13, 15, 16, 1, 2, 6, 4, 8, 14],
+            &[16, 15, 2, 1, 4, 6, 8, 14, 10, 11, 9, 7, 12, 5, 3, 13],
+            &[9, 14, 6, 8, 10, 5, 7, 11, 13, 1, 3, 12, 4, 15, 2, 16],
+            &[1, 13, 12, 3, 15, 4, 2, 16, 14, 9, 8, 6, 5, 10, 7, 11],
+            &[5, 7, 11, 10, 3, 1, 13, 12, 2, 4, 15, 16, 9, 8, 14, 6],
+            &[4, 2, 16, 15, 8, 9, 14, 6, 7, 5, 10, 11, 1, 3, 13, 12],
+        ],
+    };
+    // Needs the ALS rules a number of times, which search far more sets on a board this size
+    pub const ALS_16X16: Test = Test {
+        board: "\
+070000004005D080
+20003F01B0005G00
+G5062A0000000C00
+C0B90006000D7000
+0000500000D600A0
+0000006000001E03
+7820000FCB500000
+00G4028A0F0000B0
+80D00000E090B0C0
+0B00804G7000000E
+1A0200005C000800
+00E3650000040000
+000040050D000000
+A08001009003C000
+0065A8G000F200E0
+F200000000000AD8",
+        answer: &[
+            &[3, 7, 15, 1, 12, 11, 14, 9, 4, 6, 16, 5, 13, 2, 8, 10],
+            &[2, 13, 10, 8, 3, 15, 7, 1, 11, 9, 12, 14, 5, 16, 6, 4],
+            &[16, 5, 4, 6, 2, 10, 13, 8, 15, 1, 3, 7, 14, 12, 9, 11],
+            &[12, 14, 11, 9, 16, 4, 5, 6, 10, 8, 2, 13, 7, 3, 1, 15],
+            &[14, 1, 3, 15, 5, 12, 9, 11, 16, 4, 13, 6, 8, 7, 10, 2],
+            &[5, 9, 12, 11, 13, 16, 6, 4, 2, 10, 7, 8, 1, 14, 15, 3],
+            &[7, 8, 2, 10, 14, 3, 1, 15, 12, 11, 5, 9, 6, 13, 4, 16],
+            &[13, 6, 16, 4, 7, 2, 8, 10, 3, 15, 14, 1, 9, 5, 11, 12],
+            &[8, 4, 13, 16, 1, 7, 10, 2, 14, 3, 9, 15, 11, 6, 12, 5],
+            &[6, 11, 5, 12, 8, 13, 4, 16, 7, 2, 1, 10, 15, 9, 3, 14],
+            &[1, 10, 7, 2, 9, 14, 15, 3, 5, 12, 6, 11, 4, 8, 16, 13],
+            &[9, 15, 14, 3, 6, 5, 11, 12, 13, 16, 8, 4, 10, 1, 2, 7],
+            &[11, 3, 9, 14, 4, 6, 12, 5, 8, 13, 10, 16, 2, 15, 7, 1],
+            &[10, 16, 8, 13, 15, 1, 2, 7, 9, 14, 11, 3, 12, 4, 5, 6],
+            &[4, 12, 6, 5, 10, 8, 16, 13, 1, 7, 15, 2, 3, 11, 14, 9],
+            &[15, 2, 1, 7, 11, 9, 3, 14, 6, 5, 4, 12, 16, 10, 13, 8],
+        ],
+    };
+}
+pub mod variant_tests {
+    use super::*;
+    pub const SUDOKU_X: Test = Test {
+        board: "\
+X
+500031004
+000000050
+030008000
+000000500
+000806300
+000000968
+900000000
+000000109
+340000020",
+        answer: &[
+            &[5, 8, 7, 6, 3, 1, 2, 9, 4],
+            &[1, 9, 6, 4, 7, 2, 8, 5, 3],
+            &[4, 3, 2, 5, 9, 8, 6, 7, 1],
+            &[8, 6, 3, 7, 4, 9, 5, 1, 2],
+            &[2, 5, 9, 8, 1, 6, 3, 4, 7],
+            &[7, 1, 4, 2, 5, 3, 9, 6, 8],
+            &[9, 2, 8, 1, 6, 7, 4, 3, 5],
+            &[6, 7, 5, 3, 2, 4, 1, 8, 9],
+            &[3, 4, 1, 9, 8, 5, 7, 2, 6],
+        ],
+    };
+    pub const JIGSAW: Test = Test {
+        board: "\
+000405000
+078000000
+000200800
+000040608
+000000000
+000800054
+900000000
+207300065
+000000390
+
+AAABBBBCC
+AAABBCCCC
+DAAABBBCF
+DEEEEFFCF
+DDDEEEFCF
+DDGHHEFFF
+DDGHHEIII
+GGGHHHHHI
+GGGGIIIII",
+        answer: &[
+            &[6, 3, 1, 4, 7, 5, 2, 8, 9],
+            &[4, 7, 8, 9, 3, 6, 5, 2, 1],
+            &[7, 9, 5, 2, 6, 1, 8, 4, 3],
+            &[3, 1, 2, 5, 4, 9, 6, 7, 8],
+            &[8, 5, 4, 6, 9, 7, 1, 3, 2],
+            &[1, 6, 9, 8, 2, 3, 7, 5, 4],
+            &[9, 2, 3, 7, 5, 8, 4, 1, 6],
+            &[2, 8, 7, 3, 1, 4, 9, 6, 5],
+            &[5, 4, 6, 1, 8, 2, 3, 9, 7],
+        ],
+    };
+    pub const KILLER: Test = Test {
+        board: "\
+000000000
+000000000
+000000000
+000000000
+000000000
+000000000
+000000000
+000000000
+000000000
+
+9: r8c7 r8c8
+14: r2c5 r2c6 r1c6
+13: r4c7 r4c8
+15: r6c4 r6c3 r5c4
+5: r6c7 r6c6
+16: r5c3 r5c2
+6: r9c9 r8c9
+14: r5c9 r5c8
+12: r1c9 r2c9
+17: r9c4 r8c4 r9c3
+10: r6c5 r5c5
+20: r2c8 r3c8 r2c7
+7: r4c1 r3c1
+10: r2c4 r3c4
+12: r8c1 r7c1
+10: r1c8 r1c7
+14: r2c2 r1c2 r2c3
+12: r8c6 r8c5 r9c5
+15: r4c5 r3c5 r4c6
+8: r7c4 r7c3
+13: r8c2 r8c3 r7c2
+5: r5c6 r5c7
+7: r4c3 r4c4
+3: r7c8 r6c8
+13: r2c1 r1c1
+8: r4c9 r3c9
+13: r1c3 r1c4 r1c5
+16: r3c2 r4c2 r3c3
+9: r9c1 r9c2
+19: r9c6 r9c7 r9c8
+21: r7c6 r7c7 r7c5
+18: r5c1 r6c1 r6c2
+8: r3c7 r3c6
+13: r6c9 r7c9",
+        answer: &[
+            &[7, 1, 2, 3, 8, 5, 4, 6, 9],
+            &[6, 9, 4, 1, 2, 7, 8, 5, 3],
+            &[5, 3, 8, 9, 4, 6, 2, 7, 1],
+            &[2, 5, 1, 6, 3, 8, 9, 4, 7],
+            &[4, 7, 9, 5, 1, 2, 3, 8, 6],
+            &[8, 6, 3, 7, 9, 4, 1, 2, 5],
+            &[3, 4, 6, 2, 7, 9, 5, 1, 8],
+            &[9, 2, 7, 8, 5, 1, 6, 3, 4],
+            &[1, 8, 5, 4, 6, 3, 7, 9, 2],
         ],
     };
 }